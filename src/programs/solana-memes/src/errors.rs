@@ -417,6 +417,184 @@ pub enum CustomError {
     MultiSigGovernanceNotInitialized,
     #[msg("Emergency controls not initialized")]
     EmergencyControlsNotInitialized,
+
+    // Fair voting errors
+    #[msg("Voting power weights must sum to 100")]
+    InvalidVotingPowerWeights,
+    #[msg("Invalid staking duration multipliers")]
+    InvalidDurationMultipliers,
+    #[msg("Invalid whale thresholds")]
+    InvalidWhaleThresholds,
+    #[msg("Invalid whale cooldown period")]
+    CooldownPeriodInvalid,
+    #[msg("Invalid voting lock period")]
+    LockPeriodInvalid,
+    #[msg("Invalid minimum stake amount")]
+    MinimumStakeAmountInvalid,
+    #[msg("Invalid minimum staking duration")]
+    MinimumStakingDurationInvalid,
+    #[msg("Invalid suspicious activity threshold")]
+    SuspiciousActivityThresholdInvalid,
+    #[msg("Staked amount below the voting minimum")]
+    StakedAmountInsufficient,
+    #[msg("Staking duration below the voting minimum")]
+    StakingDurationInsufficient,
+    #[msg("Invalid reputation score")]
+    InvalidReputationScore,
+    #[msg("Invalid performance score")]
+    InvalidPerformanceScore,
+    #[msg("Invalid token price performance")]
+    TokenPricePerformanceInvalid,
+    #[msg("Invalid trading volume")]
+    TradingVolumeInvalid,
+    #[msg("Invalid community growth")]
+    CommunityGrowthInvalid,
+    #[msg("Invalid staking participation")]
+    StakingParticipationInvalid,
+    #[msg("Invalid community satisfaction")]
+    CommunitySatisfactionInvalid,
+    #[msg("Invalid marketing efforts")]
+    MarketingEffortsInvalid,
+    #[msg("Invalid community engagement")]
+    CommunityEngagementInvalid,
+    #[msg("Invalid transparency score")]
+    TransparencyScoreInvalid,
+    #[msg("Appeal threshold not met")]
+    AppealThresholdNotMet,
+    #[msg("Invalid risk score")]
+    RiskScoreInvalid,
+    #[msg("Voting power must be refreshed after the proposal opened")]
+    VotingPowerNotRefreshed,
+
+    // Launch pass errors
+    #[msg("Invalid launch pass")]
+    InvalidLaunchPass,
+    #[msg("Launch pass not held by creator")]
+    LaunchPassNotOwned,
+
+    // Symbol registry errors
+    #[msg("Invalid symbol")]
+    InvalidSymbol,
+    #[msg("Symbol already registered")]
+    SymbolTaken,
+    #[msg("Symbol reserved by another creator")]
+    SymbolReserved,
+
+    // Vesting errors
+    #[msg("Invalid vesting beneficiaries")]
+    InvalidBeneficiaries,
+    #[msg("Beneficiary vesting already created")]
+    BeneficiaryVestingExists,
+    #[msg("Vesting revocation not authorized")]
+    RevocationNotAuthorized,
+    #[msg("Vesting already tokenized")]
+    VestingAlreadyTokenized,
+    #[msg("Vesting not tokenized")]
+    VestingNotTokenized,
+    #[msg("Minimum holding period not met")]
+    HoldingPeriodNotMet,
+    #[msg("Invalid vesting transfer policy")]
+    InvalidVestingTransferPolicy,
+    #[msg("Choice window not open")]
+    ChoiceWindowNotOpen,
+
+    // Holder distribution errors
+    #[msg("Invalid merkle proof")]
+    InvalidMerkleProof,
+    #[msg("Distribution claim period ended")]
+    DistributionClaimPeriodEnded,
+    #[msg("Distribution claim period still open")]
+    DistributionClaimPeriodOpen,
+    #[msg("Distribution already swept")]
+    DistributionSwept,
+
+    // Creator policy errors
+    #[msg("Invalid creator policy")]
+    InvalidCreatorPolicy,
+    #[msg("Creator does not meet any policy tier")]
+    CreatorBelowMinimumTier,
+
+    // Inflation errors
+    #[msg("Token supply is fixed")]
+    FixedSupply,
+    #[msg("Invalid inflation schedule")]
+    InvalidInflationSchedule,
+    #[msg("Inflation cap exceeded")]
+    InflationCapExceeded,
+    #[msg("Proposal has not passed")]
+    ProposalNotPassed,
+    #[msg("Proposal does not authorize this action")]
+    ProposalMismatch,
+    #[msg("Stake was deposited after the proposal opened")]
+    StakeTooRecent,
+
+    // Bonding curve errors
+    #[msg("Invalid bonding curve parameters")]
+    InvalidBondingCurve,
+    #[msg("Bonding curve is complete")]
+    BondingCurveComplete,
+    #[msg("Slippage tolerance exceeded")]
+    SlippageExceeded,
+    #[msg("Insufficient curve liquidity")]
+    InsufficientCurveLiquidity,
+    #[msg("Bonding curve not ready to graduate")]
+    BondingCurveNotComplete,
+    #[msg("Bonding curve already graduated")]
+    AlreadyGraduated,
+
+    // Staking errors
+    #[msg("Invalid staking tier multipliers")]
+    InvalidStakingTiers,
+    #[msg("Invalid compounding vault configuration")]
+    InvalidCompoundingVault,
+    #[msg("Compounding interval not reached")]
+    CompoundTooEarly,
+    #[msg("Insufficient vault shares")]
+    InsufficientVaultShares,
+    #[msg("Liquid staking receipt not enabled")]
+    LiquidStakingNotEnabled,
+    #[msg("Liquid staking receipt already enabled")]
+    LiquidStakingAlreadyEnabled,
+    #[msg("Invalid amount")]
+    InvalidAmount,
+
+    // Fee revenue errors
+    #[msg("No fee revenue to claim")]
+    NoFeeRevenueToClaim,
+    #[msg("Fee revenue epoch still running")]
+    FeeEpochNotEnded,
+    #[msg("Invalid fee revenue epoch duration")]
+    InvalidFeeEpochDuration,
+
+    // Creator stake errors
+    #[msg("Slashing requires a passed proposal or resolved alert")]
+    SlashNotAuthorized,
+    #[msg("Slash exceeds creator stake")]
+    InsufficientCreatorStake,
+    #[msg("Invalid slashing configuration")]
+    InvalidSlashingConfig,
+    #[msg("Compensation root already posted")]
+    CompensationRootPosted,
+    #[msg("Compensation root not posted")]
+    CompensationRootNotPosted,
+    #[msg("Ban reason too long")]
+    BanReasonTooLong,
+    #[msg("Creator deregistration in progress")]
+    CreatorDeregistering,
+    #[msg("Creator deregistration not requested")]
+    DeregistrationNotRequested,
+    #[msg("Creator deregistration cooldown active")]
+    DeregistrationCooldownActive,
+    #[msg("Creator vestings not settled")]
+    VestingsNotSettled,
+    #[msg("Alert already used for a slash")]
+    AlertAlreadySlashed,
+    #[msg("Stake is counted in an open vote")]
+    StakeCommittedToVote,
+    #[msg("Vault has outstanding shares but no assets")]
+    VaultHasNoAssets,
+    #[msg("Symbol reservation limit reached for this period")]
+    SymbolReservationLimitReached,
 }
 
 // Fair voting safeguard errors
@@ -2,11 +2,72 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::CustomError;
 
+/// Fixed-point scale of `FairVotingUtils::ln_fixed` (six decimal places)
+pub const LN_SCALE: u64 = 1_000_000;
+
+/// ln(2) scaled by 10^12, rounded down
+const LN_2_E12: u128 = 693_147_180_559;
+
+/// Fractional bits computed for the binary logarithm
+const LOG2_FRACTION_BITS: u32 = 32;
+
+/// Base voting power of the staking duration component, before the tier multiplier
+const DURATION_BASE_POWER: u64 = 1_000_000;
+
 /// Fair voting utilities for implementing comprehensive safeguards
 pub struct FairVotingUtils;
 
 impl FairVotingUtils {
     
+    /// Natural logarithm of `x` in fixed point, scaled by `LN_SCALE`.
+    ///
+    /// Computes log2 with 32 fractional bits by repeated squaring of the
+    /// mantissa, then converts with ln(2) rounded down. Every step truncates,
+    /// so the result never exceeds `ln(x) * LN_SCALE` and is at most two units
+    /// (2e-6) below it. Monotonic non-decreasing; returns 0 for `x <= 1`.
+    pub fn ln_fixed(x: u64) -> u64 {
+        if x <= 1 {
+            return 0;
+        }
+        
+        let integer_part = 63 - x.leading_zeros();
+        
+        // Mantissa in [1, 2) with 32 fractional bits
+        let one = 1u128 << LOG2_FRACTION_BITS;
+        let mut mantissa = ((x as u128) << LOG2_FRACTION_BITS) >> integer_part;
+        let mut fraction: u128 = 0;
+        for bit in (0..LOG2_FRACTION_BITS).rev() {
+            mantissa = (mantissa * mantissa) >> LOG2_FRACTION_BITS;
+            if mantissa >= 2 * one {
+                mantissa >>= 1;
+                fraction |= 1 << bit;
+            }
+        }
+        
+        // log2(x) < 64 * 2^32, so the product stays below 2^79
+        let log2 = ((integer_part as u128) << LOG2_FRACTION_BITS) | fraction;
+        let ln_e12 = (log2 * LN_2_E12) >> LOG2_FRACTION_BITS;
+        (ln_e12 / (1_000_000_000_000 / LN_SCALE as u128)) as u64
+    }
+    
+    /// Integer square root, exact: the largest `r` with `r * r <= x`
+    pub fn integer_sqrt(x: u128) -> u64 {
+        if x < 2 {
+            return x as u64;
+        }
+        
+        // Newton's method from an initial guess above the root decreases monotonically
+        let bits = 128 - x.leading_zeros();
+        let mut root = 1u128 << ((bits + 1) / 2);
+        loop {
+            let next = (root + x / root) / 2;
+            if next >= root {
+                return root as u64;
+            }
+            root = next;
+        }
+    }
+    
     /// Calculate multi-factor voting power with anti-whale protections
     pub fn calculate_fair_voting_power(
         staked_amount: u64,
@@ -20,9 +81,9 @@ impl FairVotingUtils {
         safeguards: &FairVotingSafeguards,
     ) -> Result<u64> {
         // Validate input parameters
-        require!(staked_amount > 0, CustomError::STAKED_AMOUNT_INSUFFICIENT);
-        require!(staking_duration >= 0, CustomError::STAKING_DURATION_INSUFFICIENT);
-        require!(reputation_score >= -100 && reputation_score <= 1000, CustomError::INVALID_REPUTATION_SCORE);
+        require!(staked_amount > 0, CustomError::StakedAmountInsufficient);
+        require!(staking_duration >= 0, CustomError::StakingDurationInsufficient);
+        require!(reputation_score >= -100 && reputation_score <= 1000, CustomError::InvalidReputationScore);
         
         // Calculate base voting power components
         let staked_amount_power = Self::calculate_staked_amount_power(staked_amount, safeguards)?;
@@ -36,20 +97,20 @@ impl FairVotingUtils {
         let participation_power = Self::calculate_participation_power(participation_history)?;
         let quality_power = Self::calculate_quality_power(contribution_quality)?;
         
-        // Apply weighted voting power calculation
-        let weighted_power = (
-            staked_amount_power * safeguards.staked_amount_weight as u64 +
-            duration_power * safeguards.staking_duration_weight as u64 +
-            contribution_power * safeguards.community_contribution_weight as u64 +
-            holding_power * safeguards.token_holding_weight as u64
-        ) / 100;
+        // Apply weighted voting power calculation; u64 * u8 terms cannot overflow u128
+        let weighted_sum = staked_amount_power as u128 * safeguards.staked_amount_weight as u128
+            + duration_power as u128 * safeguards.staking_duration_weight as u128
+            + contribution_power as u128 * safeguards.community_contribution_weight as u128
+            + holding_power as u128 * safeguards.token_holding_weight as u128;
+        let weighted_power = weighted_sum / 100;
         
         // Apply anti-manipulation bonuses
         let anti_manipulation_bonus = (
-            consistency_power + reputation_power + participation_power + quality_power
+            consistency_power as u128 + reputation_power as u128 + participation_power as u128 + quality_power as u128
         ) / 4;
         
-        let total_power = weighted_power + anti_manipulation_bonus;
+        let total_power = u64::try_from(weighted_power + anti_manipulation_bonus)
+            .map_err(|_| CustomError::MathOverflow)?;
         
         // Apply anti-whale protections
         let final_power = Self::apply_anti_whale_protections(total_power, staked_amount, safeguards)?;
@@ -59,18 +120,15 @@ impl FairVotingUtils {
     
     /// Calculate staked amount voting power component
     fn calculate_staked_amount_power(staked_amount: u64, safeguards: &FairVotingSafeguards) -> Result<u64> {
-        require!(staked_amount >= safeguards.minimum_staked_amount, CustomError::STAKED_AMOUNT_INSUFFICIENT);
+        require!(staked_amount >= safeguards.minimum_staked_amount, CustomError::StakedAmountInsufficient);
         
         // Apply logarithmic scaling to prevent whale domination
-        let log_amount = (staked_amount as f64).ln();
-        let scaled_power = (log_amount * 1_000_000.0) as u64;
-        
-        Ok(scaled_power)
+        Ok(Self::ln_fixed(staked_amount))
     }
     
     /// Calculate duration-based voting power component
     fn calculate_duration_power(staking_duration: i64, safeguards: &FairVotingSafeguards) -> Result<u64> {
-        require!(staking_duration >= safeguards.minimum_staking_duration, CustomError::STAKING_DURATION_INSUFFICIENT);
+        require!(staking_duration >= safeguards.minimum_staking_duration, CustomError::StakingDurationInsufficient);
         
         let duration_months = staking_duration / (30 * 24 * 60 * 60); // Convert to months
         
@@ -81,49 +139,52 @@ impl FairVotingUtils {
             _ => safeguards.very_long_multiplier,
         };
         
-        let base_power = 1_000_000; // Base power for duration
-        let duration_power = base_power * multiplier as u64;
+        let duration_power = DURATION_BASE_POWER
+            .checked_mul(multiplier as u64)
+            .ok_or(CustomError::MathOverflow)?;
         
         Ok(duration_power)
     }
     
     /// Calculate community contribution voting power component
     fn calculate_contribution_power(community_contribution: u64, _safeguards: &FairVotingSafeguards) -> Result<u64> {
-        require!(community_contribution > 0, CustomError::COMMUNITY_CONTRIBUTION_INSUFFICIENT);
-        
         // Contribution power scales with contribution amount
-        let contribution_power = community_contribution * 2; // 2x multiplier for contributions
+        let contribution_power = community_contribution
+            .checked_mul(2) // 2x multiplier for contributions
+            .ok_or(CustomError::MathOverflow)?;
         
         Ok(contribution_power)
     }
     
     /// Calculate token holding voting power component
     fn calculate_holding_power(token_holding: u64, _safeguards: &FairVotingSafeguards) -> Result<u64> {
-        require!(token_holding > 0, CustomError::TOKEN_HOLDING_INSUFFICIENT);
-        
         // Holding power with diminishing returns
-        let holding_power = (token_holding as f64).sqrt() as u64 * 1_000;
+        let holding_power = Self::integer_sqrt(token_holding as u128)
+            .checked_mul(1_000)
+            .ok_or(CustomError::MathOverflow)?;
         
         Ok(holding_power)
     }
     
     /// Calculate consistency score voting power component
     fn calculate_consistency_power(consistency_score: u64) -> Result<u64> {
-        require!(consistency_score > 0, CustomError::CONSISTENCY_SCORE_INSUFFICIENT);
-        
         // Consistency power with bonus for high consistency
-        let consistency_power = if consistency_score >= 80 {
-            consistency_score * 3 // 3x bonus for high consistency
+        let multiplier = if consistency_score >= 80 {
+            3 // 3x bonus for high consistency
         } else {
-            consistency_score * 2 // 2x for moderate consistency
+            2 // 2x for moderate consistency
         };
         
+        let consistency_power = consistency_score
+            .checked_mul(multiplier)
+            .ok_or(CustomError::MathOverflow)?;
+        
         Ok(consistency_power)
     }
     
     /// Calculate reputation score voting power component
     fn calculate_reputation_power(reputation_score: i32) -> Result<u64> {
-        require!(reputation_score >= -100 && reputation_score <= 1000, CustomError::INVALID_REPUTATION_SCORE);
+        require!(reputation_score >= -100 && reputation_score <= 1000, CustomError::InvalidReputationScore);
         
         // Convert negative reputation to positive scale
         let adjusted_score = if reputation_score < 0 {
@@ -132,7 +193,7 @@ impl FairVotingUtils {
             reputation_score as u64
         };
         
-        // Reputation power with bonus for high reputation
+        // Reputation power with bonus for high reputation (score is at most 1000)
         let reputation_power = if adjusted_score >= 800 {
             adjusted_score * 4 // 4x bonus for high reputation
         } else if adjusted_score >= 500 {
@@ -146,52 +207,62 @@ impl FairVotingUtils {
     
     /// Calculate participation history voting power component
     fn calculate_participation_power(participation_history: u64) -> Result<u64> {
-        require!(participation_history > 0, CustomError::PARTICIPATION_HISTORY_INSUFFICIENT);
-        
         // Participation power with bonus for long-term participation
-        let participation_power = if participation_history >= 100 {
-            participation_history * 3 // 3x bonus for high participation
+        let multiplier = if participation_history >= 100 {
+            3 // 3x bonus for high participation
         } else {
-            participation_history * 2 // 2x for moderate participation
+            2 // 2x for moderate participation
         };
         
+        let participation_power = participation_history
+            .checked_mul(multiplier)
+            .ok_or(CustomError::MathOverflow)?;
+        
         Ok(participation_power)
     }
     
     /// Calculate contribution quality voting power component
     fn calculate_quality_power(contribution_quality: u64) -> Result<u64> {
-        require!(contribution_quality > 0, CustomError::CONTRIBUTION_QUALITY_INSUFFICIENT);
-        
         // Quality power with bonus for high quality contributions
-        let quality_power = if contribution_quality >= 80 {
-            contribution_quality * 3 // 3x bonus for high quality
+        let multiplier = if contribution_quality >= 80 {
+            3 // 3x bonus for high quality
         } else {
-            contribution_quality * 2 // 2x for moderate quality
+            2 // 2x for moderate quality
         };
         
+        let quality_power = contribution_quality
+            .checked_mul(multiplier)
+            .ok_or(CustomError::MathOverflow)?;
+        
         Ok(quality_power)
     }
     
-    /// Apply anti-whale protections to voting power
+    /// Apply anti-whale protections to voting power.
+    ///
+    /// Power above the whale threshold (half the per-wallet maximum) is
+    /// discounted by `whale_voting_discount`, and the result is capped at
+    /// `max_voting_power_per_wallet`. Discounting only the excess keeps the
+    /// result monotonic in `voting_power` and never above it.
     fn apply_anti_whale_protections(
         voting_power: u64,
-        staked_amount: u64,
+        _staked_amount: u64,
         safeguards: &FairVotingSafeguards,
     ) -> Result<u64> {
-        // Check if wallet exceeds maximum voting power
-        if voting_power > safeguards.max_voting_power_per_wallet {
-            return Ok(safeguards.max_voting_power_per_wallet);
+        let max_power = safeguards.max_voting_power_per_wallet;
+        let whale_threshold = max_power / 2; // 50% of max
+        if voting_power <= whale_threshold {
+            return Ok(voting_power);
         }
         
-        // Apply whale discount if applicable
-        let whale_threshold = safeguards.max_voting_power_per_wallet / 2; // 50% of max
-        if voting_power > whale_threshold {
-            let discount = safeguards.whale_voting_discount;
-            let discounted_power = voting_power * (100 - discount) as u64 / 100;
-            return Ok(discounted_power);
-        }
+        // Apply whale discount to the power above the threshold
+        let discount = safeguards.whale_voting_discount.min(100) as u128;
+        let excess = (voting_power - whale_threshold) as u128;
+        let discounted_excess = excess * (100 - discount) / 100;
+        let discounted_power = u64::try_from(whale_threshold as u128 + discounted_excess)
+            .map_err(|_| CustomError::MathOverflow)?;
         
-        Ok(voting_power)
+        // Check if wallet exceeds maximum voting power
+        Ok(discounted_power.min(max_power))
     }
     
     /// Check if wallet is flagged as whale
@@ -200,8 +271,12 @@ impl FairVotingUtils {
         total_supply: u64,
         safeguards: &FairVotingSafeguards,
     ) -> Result<bool> {
-        let concentration_percentage = (staked_amount * 100) / total_supply;
-        let is_whale = concentration_percentage > safeguards.max_concentration_percent as u64;
+        if total_supply == 0 {
+            return Ok(false);
+        }
+        
+        let concentration_percentage = staked_amount as u128 * 100 / total_supply as u128;
+        let is_whale = concentration_percentage > safeguards.max_concentration_percent as u128;
         
         Ok(is_whale)
     }
@@ -212,7 +287,7 @@ impl FairVotingUtils {
         current_time: i64,
         safeguards: &FairVotingSafeguards,
     ) -> Result<bool> {
-        let time_since_last_vote = current_time - last_vote_time;
+        let time_since_last_vote = current_time.saturating_sub(last_vote_time);
         let under_cooldown = time_since_last_vote < safeguards.whale_cooldown_period;
         
         Ok(under_cooldown)
@@ -221,21 +296,21 @@ impl FairVotingUtils {
     /// Calculate creator performance score
     pub fn calculate_performance_score(performance: &CreatorPerformance) -> Result<u64> {
         // Validate performance metrics
-        require!(performance.token_price_performance > 0, CustomError::TOKEN_PRICE_PERFORMANCE_INVALID);
-        require!(performance.trading_volume > 0, CustomError::TRADING_VOLUME_INVALID);
-        require!(performance.community_growth > 0, CustomError::COMMUNITY_GROWTH_INVALID);
-        require!(performance.staking_participation > 0, CustomError::STAKING_PARTICIPATION_INVALID);
-        require!(performance.community_satisfaction <= 100, CustomError::COMMUNITY_SATISFACTION_INVALID);
-        require!(performance.marketing_efforts <= 100, CustomError::MARKETING_EFFORTS_INVALID);
-        require!(performance.community_engagement <= 100, CustomError::COMMUNITY_ENGAGEMENT_INVALID);
-        require!(performance.transparency_score <= 100, CustomError::TRANSPARENCY_SCORE_INVALID);
-        
-        // Calculate quantitative score (70% weight)
+        require!(performance.token_price_performance > 0, CustomError::TokenPricePerformanceInvalid);
+        require!(performance.trading_volume > 0, CustomError::TradingVolumeInvalid);
+        require!(performance.community_growth > 0, CustomError::CommunityGrowthInvalid);
+        require!(performance.staking_participation > 0, CustomError::StakingParticipationInvalid);
+        require!(performance.community_satisfaction <= 100, CustomError::CommunitySatisfactionInvalid);
+        require!(performance.marketing_efforts <= 100, CustomError::MarketingEffortsInvalid);
+        require!(performance.community_engagement <= 100, CustomError::CommunityEngagementInvalid);
+        require!(performance.transparency_score <= 100, CustomError::TransparencyScoreInvalid);
+        
+        // Calculate quantitative score (70% weight); unbounded metrics are widened to u128
         let quantitative_score = (
-            performance.token_price_performance * 25 +
-            performance.trading_volume * 20 +
-            performance.community_growth * 15 +
-            performance.staking_participation * 10
+            performance.token_price_performance as u128 * 25 +
+            performance.trading_volume as u128 * 20 +
+            performance.community_growth as u128 * 15 +
+            performance.staking_participation as u128 * 10
         ) / 70;
         
         // Calculate qualitative score (30% weight)
@@ -247,17 +322,17 @@ impl FairVotingUtils {
         ) / 30;
         
         // Calculate overall performance score
-        let overall_score = (quantitative_score * 70 + qualitative_score * 30) / 100;
+        let overall_score = (quantitative_score * 70 + qualitative_score as u128 * 30) / 100;
         
         // Ensure score is within valid range (0-100)
-        let final_score = std::cmp::min(overall_score, 100);
+        let final_score = std::cmp::min(overall_score, 100) as u64;
         
         Ok(final_score)
     }
     
     /// Determine creator release percentage based on performance
     pub fn determine_creator_release_percentage(performance_score: u64) -> Result<u8> {
-        require!(performance_score <= 100, CustomError::INVALID_PERFORMANCE_SCORE);
+        require!(performance_score <= 100, CustomError::InvalidPerformanceScore);
         
         let release_percentage = match performance_score {
             90..=100 => 100, // Full release for excellent performance
@@ -279,10 +354,10 @@ impl FairVotingUtils {
         total_votes: u64,
         appeal_threshold: u8,
     ) -> Result<bool> {
-        require!(total_votes > 0, CustomError::APPEAL_THRESHOLD_NOT_MET);
+        require!(total_votes > 0, CustomError::AppealThresholdNotMet);
         
-        let against_percentage = (votes_against * 100) / total_votes;
-        let threshold_met = against_percentage >= appeal_threshold as u64;
+        let against_percentage = votes_against as u128 * 100 / total_votes as u128;
+        let threshold_met = against_percentage >= appeal_threshold as u128;
         
         Ok(threshold_met)
     }
@@ -293,7 +368,7 @@ impl FairVotingUtils {
         current_time: i64,
         appeal_period: i64,
     ) -> Result<bool> {
-        let time_since_decision = current_time - decision_time;
+        let time_since_decision = current_time.saturating_sub(decision_time);
         let period_active = time_since_decision <= appeal_period;
         
         Ok(period_active)
@@ -305,7 +380,7 @@ impl FairVotingUtils {
         evidence_strength: u8,
         historical_violations: u64,
     ) -> Result<u8> {
-        require!(evidence_strength <= 100, CustomError::RISK_SCORE_INVALID);
+        require!(evidence_strength <= 100, CustomError::RiskScoreInvalid);
         
         let base_risk = match alert_type {
             AlertType::WhaleManipulation => 70,
@@ -322,7 +397,7 @@ impl FairVotingUtils {
         let evidence_adjustment = (evidence_strength as i32 - 50) * 2; // -100 to +100
         
         // Adjust risk based on historical violations
-        let violation_adjustment = (historical_violations as i32 * 5).min(50); // +5 per violation, max +50
+        let violation_adjustment = (historical_violations.min(10) as i32) * 5; // +5 per violation, max +50
         
         let adjusted_risk = (base_risk as i32 + evidence_adjustment + violation_adjustment)
             .max(0)
@@ -333,7 +408,7 @@ impl FairVotingUtils {
     
     /// Determine penalty type based on risk score
     pub fn determine_penalty_type(risk_score: u8) -> Result<PenaltyType> {
-        require!(risk_score <= 100, CustomError::RISK_SCORE_INVALID);
+        require!(risk_score <= 100, CustomError::RiskScoreInvalid);
         
         let penalty_type = match risk_score {
             0..=30 => PenaltyType::Warning,
@@ -354,28 +429,250 @@ impl FairVotingUtils {
                           safeguards.staking_duration_weight as u16 +
                           safeguards.community_contribution_weight as u16 +
                           safeguards.token_holding_weight as u16;
-        require!(total_weight == 100, CustomError::INVALID_VOTING_POWER_WEIGHTS);
+        require!(total_weight == 100, CustomError::InvalidVotingPowerWeights);
         
         // Validate duration multipliers
-        require!(safeguards.short_term_multiplier > 0, CustomError::INVALID_DURATION_MULTIPLIERS);
-        require!(safeguards.medium_term_multiplier > 0, CustomError::INVALID_DURATION_MULTIPLIERS);
-        require!(safeguards.long_term_multiplier > 0, CustomError::INVALID_DURATION_MULTIPLIERS);
-        require!(safeguards.very_long_multiplier > 0, CustomError::INVALID_DURATION_MULTIPLIERS);
+        require!(safeguards.short_term_multiplier > 0, CustomError::InvalidDurationMultipliers);
+        require!(safeguards.medium_term_multiplier > 0, CustomError::InvalidDurationMultipliers);
+        require!(safeguards.long_term_multiplier > 0, CustomError::InvalidDurationMultipliers);
+        require!(safeguards.very_long_multiplier > 0, CustomError::InvalidDurationMultipliers);
         
         // Validate whale thresholds
-        require!(safeguards.max_voting_power_per_wallet > 0, CustomError::INVALID_WHALE_THRESHOLDS);
-        require!(safeguards.whale_voting_discount <= 100, CustomError::INVALID_WHALE_THRESHOLDS);
-        require!(safeguards.max_concentration_percent <= 100, CustomError::INVALID_WHALE_THRESHOLDS);
+        require!(safeguards.max_voting_power_per_wallet > 0, CustomError::InvalidWhaleThresholds);
+        require!(safeguards.whale_voting_discount <= 100, CustomError::InvalidWhaleThresholds);
+        require!(safeguards.max_concentration_percent <= 100, CustomError::InvalidWhaleThresholds);
         
         // Validate time-based requirements
-        require!(safeguards.minimum_staking_duration > 0, CustomError::MINIMUM_STAKING_DURATION_INVALID);
-        require!(safeguards.minimum_staked_amount > 0, CustomError::MINIMUM_STAKE_AMOUNT_INVALID);
-        require!(safeguards.lock_period_during_voting >= 0, CustomError::LOCK_PERIOD_INVALID);
-        require!(safeguards.whale_cooldown_period >= 0, CustomError::COOLDOWN_PERIOD_INVALID);
+        require!(safeguards.minimum_staking_duration > 0, CustomError::MinimumStakingDurationInvalid);
+        require!(safeguards.minimum_staked_amount > 0, CustomError::MinimumStakeAmountInvalid);
+        require!(safeguards.lock_period_during_voting >= 0, CustomError::LockPeriodInvalid);
+        require!(safeguards.whale_cooldown_period >= 0, CustomError::CooldownPeriodInvalid);
         
         // Validate thresholds
-        require!(safeguards.suspicious_activity_threshold > 0, CustomError::SUSPICIOUS_ACTIVITY_THRESHOLD_INVALID);
+        require!(safeguards.suspicious_activity_threshold > 0, CustomError::SuspiciousActivityThresholdInvalid);
         
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const SAMPLES: usize = 10_000;
+    const DAY: i64 = 24 * 60 * 60;
+    
+    /// Deterministic xorshift64* generator so failures reproduce
+    struct Rng(u64);
+    
+    impl Rng {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 >> 12;
+            self.0 ^= self.0 << 25;
+            self.0 ^= self.0 >> 27;
+            self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+        }
+        
+        /// Values spread across all magnitudes rather than clustered near u64::MAX
+        fn any_magnitude(&mut self) -> u64 {
+            let shift = self.next() % 64;
+            self.next() >> shift
+        }
+    }
+    
+    fn safeguards(max_voting_power_per_wallet: u64, whale_voting_discount: u8) -> FairVotingSafeguards {
+        FairVotingSafeguards {
+            authority: Pubkey::default(),
+            staked_amount_weight: 40,
+            staking_duration_weight: 25,
+            community_contribution_weight: 20,
+            token_holding_weight: 15,
+            max_voting_power_per_wallet,
+            whale_voting_discount,
+            max_concentration_percent: 5,
+            whale_cooldown_period: DAY,
+            minimum_staking_duration: 30 * DAY,
+            minimum_staked_amount: 1,
+            lock_period_during_voting: 0,
+            short_term_multiplier: 1,
+            medium_term_multiplier: 2,
+            long_term_multiplier: 3,
+            very_long_multiplier: 4,
+            suspicious_activity_threshold: 1,
+            manipulation_detection_enabled: true,
+            automated_monitoring_enabled: true,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+    
+    fn voting_power(staked_amount: u64, token_holding: u64, safeguards: &FairVotingSafeguards) -> u64 {
+        FairVotingUtils::calculate_fair_voting_power(
+            staked_amount,
+            90 * DAY,
+            50_000,
+            token_holding,
+            85,
+            750,
+            50,
+            80,
+            safeguards,
+        )
+        .unwrap()
+    }
+    
+    #[test]
+    fn ln_fixed_is_within_two_units_below_ln() {
+        let mut rng = Rng(0x5eed_0001);
+        // 9_261_672_107_561_948 truncates to just over one unit below ln(x)
+        let edges = [1, 2, 3, 10, 1_000_000, u32::MAX as u64, 9_261_672_107_561_948, 1 << 63, u64::MAX];
+        for x in edges.iter().copied().chain((0..SAMPLES).map(|_| rng.any_magnitude().max(1))) {
+            let exact = (x as f64).ln() * LN_SCALE as f64;
+            let approx = FairVotingUtils::ln_fixed(x) as f64;
+            assert!(approx <= exact + 1e-6, "ln_fixed({}) = {} above {}", x, approx, exact);
+            assert!(exact - approx <= 2.0, "ln_fixed({}) = {} too far below {}", x, approx, exact);
+        }
+    }
+    
+    #[test]
+    fn ln_fixed_is_monotonic() {
+        let mut rng = Rng(0x5eed_0002);
+        for _ in 0..SAMPLES {
+            let (a, b) = (rng.any_magnitude(), rng.any_magnitude());
+            let (low, high) = (a.min(b), a.max(b));
+            assert!(FairVotingUtils::ln_fixed(low) <= FairVotingUtils::ln_fixed(high));
+            assert!(FairVotingUtils::ln_fixed(high) <= FairVotingUtils::ln_fixed(high.saturating_add(1)));
+        }
+        for x in 1..10_000u64 {
+            assert!(FairVotingUtils::ln_fixed(x) <= FairVotingUtils::ln_fixed(x + 1));
+        }
+    }
+    
+    #[test]
+    fn integer_sqrt_is_exact_floor() {
+        let mut rng = Rng(0x5eed_0003);
+        let edges = [0, 1, 2, 3, 4, 15, 16, u32::MAX as u128, u64::MAX as u128, u128::MAX];
+        let samples = (0..SAMPLES).map(|_| (rng.any_magnitude() as u128) * (rng.any_magnitude() as u128));
+        for x in edges.iter().copied().chain(samples) {
+            let root = FairVotingUtils::integer_sqrt(x) as u128;
+            assert!(root * root <= x, "sqrt({}) = {} too high", x, root);
+            assert!(root == u64::MAX as u128 || (root + 1) * (root + 1) > x, "sqrt({}) = {} too low", x, root);
+        }
+    }
+    
+    #[test]
+    fn anti_whale_respects_caps() {
+        let mut rng = Rng(0x5eed_0004);
+        for _ in 0..SAMPLES {
+            let max_power = rng.any_magnitude().max(1);
+            let discount = (rng.next() % 101) as u8;
+            let config = safeguards(max_power, discount);
+            let power = rng.any_magnitude();
+            
+            let protected = FairVotingUtils::apply_anti_whale_protections(power, 0, &config).unwrap();
+            
+            // Never above the per-wallet cap or the unprotected power
+            assert!(protected <= max_power);
+            assert!(protected <= power);
+            
+            // Untouched up to the whale threshold
+            let whale_threshold = max_power / 2;
+            if power <= whale_threshold {
+                assert_eq!(protected, power);
+            } else {
+                // Power above the threshold keeps at most (100 - discount)% of its weight
+                let excess = (power - whale_threshold) as u128;
+                assert!(protected as u128 <= whale_threshold as u128 + excess * (100 - discount as u128) / 100);
+                assert!(protected >= whale_threshold);
+            }
+        }
+    }
+    
+    #[test]
+    fn anti_whale_is_monotonic() {
+        let mut rng = Rng(0x5eed_0005);
+        for _ in 0..SAMPLES {
+            let config = safeguards(rng.any_magnitude().max(1), (rng.next() % 101) as u8);
+            let (a, b) = (rng.any_magnitude(), rng.any_magnitude());
+            let (low, high) = (a.min(b), a.max(b));
+            assert!(
+                FairVotingUtils::apply_anti_whale_protections(low, 0, &config).unwrap()
+                    <= FairVotingUtils::apply_anti_whale_protections(high, 0, &config).unwrap()
+            );
+        }
+        
+        // Crossing the threshold and the cap never lowers power
+        let config = safeguards(1_000, 50);
+        let mut previous = 0;
+        for power in 0..=2_000u64 {
+            let protected = FairVotingUtils::apply_anti_whale_protections(power, 0, &config).unwrap();
+            assert!(protected >= previous, "power {} dropped to {}", power, protected);
+            previous = protected;
+        }
+    }
+    
+    #[test]
+    fn voting_power_is_monotonic_in_stake_and_holding() {
+        let mut rng = Rng(0x5eed_0006);
+        let config = safeguards(100_000_000, 50);
+        for _ in 0..SAMPLES {
+            let (a, b) = (rng.any_magnitude().max(1), rng.any_magnitude().max(1));
+            let (low, high) = (a.min(b), a.max(b));
+            let holding = rng.any_magnitude();
+            assert!(voting_power(low, holding, &config) <= voting_power(high, holding, &config));
+            
+            let staked = rng.any_magnitude().max(1);
+            assert!(voting_power(staked, low, &config) <= voting_power(staked, high, &config));
+        }
+    }
+    
+    #[test]
+    fn voting_power_never_exceeds_wallet_cap() {
+        let mut rng = Rng(0x5eed_0007);
+        for _ in 0..SAMPLES {
+            let config = safeguards(rng.any_magnitude().max(1), (rng.next() % 101) as u8);
+            let power = voting_power(rng.any_magnitude().max(1), rng.any_magnitude(), &config);
+            assert!(power <= config.max_voting_power_per_wallet);
+        }
+    }
+    
+    #[test]
+    fn voting_power_is_monotonic_in_duration() {
+        let power_at = |days: i64, config: &FairVotingSafeguards| {
+            FairVotingUtils::calculate_fair_voting_power(
+                1_000_000, days * DAY, 50_000, 100_000, 85, 750, 50, 80, config,
+            )
+            .unwrap()
+        };
+        
+        // Caps around the uncapped range put the whale discount and the cap in play
+        let uncapped = safeguards(u64::MAX, 50);
+        let (low, high) = (power_at(30, &uncapped), power_at(720, &uncapped));
+        assert!(low < high);
+        for max_power in [u64::MAX, high * 2, high, (low + high) / 2, low] {
+            let config = safeguards(max_power, 50);
+            let mut previous = 0;
+            for days in (30..=720).step_by(15) {
+                let power = power_at(days, &config);
+                assert!(power >= previous, "cap {}: {} days dropped to {}", max_power, days, power);
+                previous = power;
+            }
+        }
+    }
+    
+    #[test]
+    fn extreme_inputs_overflow_with_error_instead_of_wrapping() {
+        let config = safeguards(u64::MAX, 0);
+        let result = FairVotingUtils::calculate_fair_voting_power(
+            u64::MAX, i64::MAX, u64::MAX, u64::MAX, u64::MAX, 1000, u64::MAX, u64::MAX, &config,
+        );
+        assert!(result.is_err());
+        
+        let capped = safeguards(1_000_000, 50);
+        let power = FairVotingUtils::calculate_fair_voting_power(
+            u64::MAX, i64::MAX, u64::MAX / 1_000, u64::MAX, 100, 1000, 1_000, 100, &capped,
+        )
+        .unwrap();
+        assert!(power <= 1_000_000);
+    }
+}
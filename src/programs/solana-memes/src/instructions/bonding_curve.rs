@@ -0,0 +1,474 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct BuyFromCurve<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = mint
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve_sol_vault", mint.key().as_ref()],
+        bump = bonding_curve.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        seeds = [b"anti_bot_config", mint.key().as_ref()],
+        bump = anti_bot_config.bump
+    )]
+    pub anti_bot_config: Account<'info, AntiBotConfig>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.authority == platform_config.authority @ CustomError::InvalidAccount
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        associated_token::mint = mint,
+        associated_token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + CurveTrader::LEN,
+        seeds = [b"curve_trader", mint.key().as_ref(), buyer.key().as_ref()],
+        bump
+    )]
+    pub curve_trader: Account<'info, CurveTrader>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SellToCurve<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = mint
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve_sol_vault", mint.key().as_ref()],
+        bump = bonding_curve.sol_vault_bump
+    )]
+    pub sol_vault: SystemAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"anti_bot_config", mint.key().as_ref()],
+        bump = anti_bot_config.bump
+    )]
+    pub anti_bot_config: Account<'info, AntiBotConfig>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.authority == platform_config.authority @ CustomError::InvalidAccount
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = seller
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + CurveTrader::LEN,
+        seeds = [b"curve_trader", mint.key().as_ref(), seller.key().as_ref()],
+        bump
+    )]
+    pub curve_trader: Account<'info, CurveTrader>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CurveTrade {
+    pub mint: Pubkey,
+    pub trader: Pubkey,
+    pub is_buy: bool,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub fee: u64,
+    pub virtual_sol_reserves: u64,
+    pub virtual_token_reserves: u64,
+    pub timestamp: i64,
+}
+
+pub fn buy(ctx: Context<BuyFromCurve>, sol_amount: u64, min_tokens_out: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let fee_rate = ctx.accounts.platform_config.trading_fee_percentage as u64;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    require!(!bonding_curve.complete, CustomError::BondingCurveComplete);
+    require!(sol_amount > 0, CustomError::InvalidAmount);
+
+    // Fee is taken from the SOL paid in (per-mille, as in collect_trading_fee)
+    let mut fee = sol_amount
+        .checked_mul(fee_rate)
+        .ok_or(CustomError::MathOverflow)?
+        / 1000;
+    let mut sol_in = sol_amount.checked_sub(fee).ok_or(CustomError::MathOverflow)?;
+    let mut tokens_out = bonding_curve
+        .tokens_out_for_sol(sol_in)
+        .ok_or(CustomError::MathOverflow)?;
+
+    // Last buyer takes whatever is left and only pays for that
+    if tokens_out > bonding_curve.real_token_reserves {
+        tokens_out = bonding_curve.real_token_reserves;
+        sol_in = bonding_curve
+            .sol_in_for_tokens(tokens_out)
+            .ok_or(CustomError::MathOverflow)?;
+        fee = sol_in.checked_mul(fee_rate).ok_or(CustomError::MathOverflow)? / 1000;
+    }
+
+    require!(tokens_out > 0, CustomError::InsufficientCurveLiquidity);
+    require!(tokens_out >= min_tokens_out, CustomError::SlippageExceeded);
+
+    // Anti-bot limits apply to curve trades the same way as transfers
+    let anti_bot = &ctx.accounts.anti_bot_config;
+    check_anti_bot_limits(anti_bot, &ctx.accounts.buyer.key(), tokens_out)?;
+    check_trade_cooldown(anti_bot, &ctx.accounts.curve_trader, clock.unix_timestamp)?;
+    if anti_bot.enabled {
+        let max_wallet = (ctx.accounts.token_metadata.total_supply as u128)
+            .checked_mul(anti_bot.max_wallet_percentage as u128)
+            .ok_or(CustomError::MathOverflow)?
+            / 100;
+        let balance_after = ctx.accounts.buyer_token_account.amount
+            .checked_add(tokens_out)
+            .ok_or(CustomError::MathOverflow)?;
+        require!(
+            balance_after as u128 <= max_wallet,
+            CustomError::MaxWalletPercentageExceeded
+        );
+    }
+
+    // Pay SOL into the curve vault and the fee into the treasury
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.sol_vault.to_account_info(),
+            },
+        ),
+        sol_in,
+    )?;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            fee,
+        )?;
+    }
+
+    // Release tokens from the curve vault
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[b"bonding_curve".as_ref(), mint_key.as_ref(), &[bonding_curve.bump]];
+    let signer = &[&seeds[..]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.curve_token_vault.to_account_info(),
+                to: ctx.accounts.buyer_token_account.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            signer,
+        ),
+        tokens_out,
+    )?;
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.virtual_sol_reserves = bonding_curve.virtual_sol_reserves
+        .checked_add(sol_in)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.virtual_token_reserves = bonding_curve.virtual_token_reserves
+        .checked_sub(tokens_out)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.real_sol_reserves = bonding_curve.real_sol_reserves
+        .checked_add(sol_in)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.real_token_reserves = bonding_curve.real_token_reserves
+        .checked_sub(tokens_out)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.total_volume = bonding_curve.total_volume
+        .checked_add(sol_in)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.total_fees = bonding_curve.total_fees
+        .checked_add(fee)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.last_trade_time = clock.unix_timestamp;
+
+    // Close the curve once it sells out or reaches the graduation market cap
+    let market_cap = bonding_curve
+        .market_cap(ctx.accounts.token_metadata.total_supply)
+        .ok_or(CustomError::MathOverflow)?;
+    if bonding_curve.real_token_reserves == 0 || market_cap >= bonding_curve.graduation_market_cap {
+        bonding_curve.complete = true;
+    }
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.fee_collection_stats.total_trading_fees = treasury.fee_collection_stats.total_trading_fees
+        .checked_add(fee)
+        .ok_or(CustomError::MathOverflow)?;
+    treasury.last_updated = clock.unix_timestamp;
+
+    // Start the wallet's cooldown
+    record_trade(
+        &mut ctx.accounts.curve_trader,
+        mint_key,
+        ctx.accounts.buyer.key(),
+        *ctx.bumps.get("curve_trader").unwrap(),
+        clock.unix_timestamp,
+    );
+
+    emit!(CurveTrade {
+        mint: mint_key,
+        trader: ctx.accounts.buyer.key(),
+        is_buy: true,
+        sol_amount: sol_in,
+        token_amount: tokens_out,
+        fee,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Bought {} tokens for {} lamports (fee {})", tokens_out, sol_in, fee);
+
+    Ok(())
+}
+
+pub fn sell(ctx: Context<SellToCurve>, token_amount: u64, min_sol_out: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let fee_rate = ctx.accounts.platform_config.trading_fee_percentage as u64;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    require!(!bonding_curve.complete, CustomError::BondingCurveComplete);
+    require!(token_amount > 0, CustomError::InvalidAmount);
+
+    check_anti_bot_limits(&ctx.accounts.anti_bot_config, &ctx.accounts.seller.key(), token_amount)?;
+    check_trade_cooldown(&ctx.accounts.anti_bot_config, &ctx.accounts.curve_trader, clock.unix_timestamp)?;
+
+    let sol_out = bonding_curve
+        .sol_out_for_tokens(token_amount)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(sol_out <= bonding_curve.real_sol_reserves, CustomError::InsufficientCurveLiquidity);
+
+    // Fee is taken from the SOL paid out
+    let fee = sol_out.checked_mul(fee_rate).ok_or(CustomError::MathOverflow)? / 1000;
+    let sol_to_seller = sol_out.checked_sub(fee).ok_or(CustomError::MathOverflow)?;
+    require!(sol_to_seller > 0, CustomError::InsufficientCurveLiquidity);
+    require!(sol_to_seller >= min_sol_out, CustomError::SlippageExceeded);
+
+    // Return tokens to the curve vault
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.seller_token_account.to_account_info(),
+                to: ctx.accounts.curve_token_vault.to_account_info(),
+                authority: ctx.accounts.seller.to_account_info(),
+            },
+        ),
+        token_amount,
+    )?;
+
+    // Pay out SOL from the vault
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"bonding_curve_sol_vault".as_ref(),
+        mint_key.as_ref(),
+        &[bonding_curve.sol_vault_bump],
+    ];
+    let signer = &[&seeds[..]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.sol_vault.to_account_info(),
+                to: ctx.accounts.seller.to_account_info(),
+            },
+            signer,
+        ),
+        sol_to_seller,
+    )?;
+    if fee > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.sol_vault.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+                signer,
+            ),
+            fee,
+        )?;
+    }
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.virtual_sol_reserves = bonding_curve.virtual_sol_reserves
+        .checked_sub(sol_out)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.virtual_token_reserves = bonding_curve.virtual_token_reserves
+        .checked_add(token_amount)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.real_sol_reserves = bonding_curve.real_sol_reserves
+        .checked_sub(sol_out)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.real_token_reserves = bonding_curve.real_token_reserves
+        .checked_add(token_amount)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.total_volume = bonding_curve.total_volume
+        .checked_add(sol_out)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.total_fees = bonding_curve.total_fees
+        .checked_add(fee)
+        .ok_or(CustomError::MathOverflow)?;
+    bonding_curve.last_trade_time = clock.unix_timestamp;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.fee_collection_stats.total_trading_fees = treasury.fee_collection_stats.total_trading_fees
+        .checked_add(fee)
+        .ok_or(CustomError::MathOverflow)?;
+    treasury.last_updated = clock.unix_timestamp;
+
+    // Start the wallet's cooldown
+    record_trade(
+        &mut ctx.accounts.curve_trader,
+        mint_key,
+        ctx.accounts.seller.key(),
+        *ctx.bumps.get("curve_trader").unwrap(),
+        clock.unix_timestamp,
+    );
+
+    emit!(CurveTrade {
+        mint: mint_key,
+        trader: ctx.accounts.seller.key(),
+        is_buy: false,
+        sol_amount: sol_out,
+        token_amount,
+        fee,
+        virtual_sol_reserves: bonding_curve.virtual_sol_reserves,
+        virtual_token_reserves: bonding_curve.virtual_token_reserves,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Sold {} tokens for {} lamports (fee {})", token_amount, sol_to_seller, fee);
+
+    Ok(())
+}
+
+fn check_anti_bot_limits(anti_bot: &AntiBotConfig, trader: &Pubkey, token_amount: u64) -> Result<()> {
+    if anti_bot.enabled {
+        require!(
+            token_amount <= anti_bot.max_transaction_size,
+            CustomError::TransactionTooLarge
+        );
+        require!(
+            token_amount >= anti_bot.min_transaction_size,
+            CustomError::TransactionTooSmall
+        );
+        require!(
+            !anti_bot.blacklisted_addresses.contains(trader),
+            CustomError::AddressBlacklisted
+        );
+    }
+    Ok(())
+}
+
+/// Enforce the per-wallet cooldown between curve trades
+fn check_trade_cooldown(anti_bot: &AntiBotConfig, curve_trader: &CurveTrader, current_time: i64) -> Result<()> {
+    if anti_bot.enabled && curve_trader.last_trade_time > 0 {
+        require!(
+            current_time >= curve_trader.last_trade_time + anti_bot.cooldown_period,
+            CustomError::TradeTooFrequent
+        );
+    }
+    Ok(())
+}
+
+fn record_trade(
+    curve_trader: &mut CurveTrader,
+    mint: Pubkey,
+    trader: Pubkey,
+    bump: u8,
+    current_time: i64,
+) {
+    curve_trader.mint = mint;
+    curve_trader.trader = trader;
+    curve_trader.last_trade_time = current_time;
+    curve_trader.bump = bump;
+}
@@ -1,158 +1,160 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
 use crate::state::*;
+use crate::errors::CustomError;
+use crate::instructions::vesting_position::authorize_holder;
 
 #[derive(Accounts)]
 pub struct ChooseVestingOption<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting.mint.as_ref(), vesting.beneficiary.as_ref()],
+        bump = vesting.bump
+    )]
     pub vesting: Account<'info, Vesting>,
+
     pub creator: Signer<'info>,
-    #[account(mut)]
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = creator
+    )]
     pub creator_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vesting
+    )]
     pub vesting_token_account: Account<'info, TokenAccount>,
-    #[account(mut)]
-    pub treasury: Account<'info, PlatformTreasury>,
+
+    #[account(
+        mut,
+        address = vesting.mint @ CustomError::InvalidAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    /// Chooser's position NFT account; required once the vesting is tokenized
+    pub position_account: Option<Account<'info, TokenAccount>>,
+
     pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<ChooseVestingOption>, option: VestingOption) -> Result<()> {
-    let vesting = &mut ctx.accounts.vesting;
     let clock = Clock::get()?;
-    
-    // Verify creator ownership
-    require!(
-        vesting.owner == ctx.accounts.creator.key(),
-        CustomError::Unauthorized
-    );
-    
-    // Check if vesting period has ended
+
+    // Verify the chooser owns the vesting and, once tokenized, holds its position NFT
+    authorize_holder(
+        &ctx.accounts.vesting,
+        ctx.accounts.creator.key(),
+        ctx.accounts.position_account.as_deref(),
+    )?;
+    let vesting = &ctx.accounts.vesting;
+    require!(!vesting.revoked, CustomError::VestingRevoked);
+
+    // The choice opens once performance-held tokens have unlocked
     require!(
-        clock.unix_timestamp >= vesting.cliff_time,
-        CustomError::VestingNotAvailable
+        clock.unix_timestamp >= vesting.end_time + PERFORMANCE_RELEASE_DEADLINE,
+        CustomError::ChoiceWindowNotOpen
     );
-    
+
     // Check if choice deadline hasn't passed
     require!(
         clock.unix_timestamp <= vesting.choice_deadline,
         CustomError::ChoiceDeadlinePassed
     );
-    
+
     // Check if choice hasn't already been made
     require!(
         !vesting.choice_made,
         CustomError::ChoiceAlreadyMade
     );
-    
-    // Set the distribution choice
-    vesting.distribution_choice = Some(option);
-    vesting.choice_made = true;
-    
-    // Calculate claimable amount
-    let claimable_amount = vesting.amount - vesting.released;
-    
+
+    // Calculate remaining amount
+    let claimable_amount = vesting.amount
+        .checked_sub(vesting.released)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(claimable_amount > 0, CustomError::NothingToClaim);
+
+    let mint_key = vesting.mint;
+    let beneficiary_key = vesting.beneficiary;
+    let seeds = &[b"vesting".as_ref(), mint_key.as_ref(), beneficiary_key.as_ref(), &[vesting.bump]];
+    let signer = &[&seeds[..]];
+
+    let creator_amount = match option {
+        VestingOption::Withdraw => claimable_amount,
+        VestingOption::Burn | VestingOption::Distribute => claimable_amount - claimable_amount / 2,
+    };
+    let held_amount = claimable_amount - creator_amount;
+
+    // Creator's share leaves the vesting vault in every case
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vesting_token_account.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.vesting.to_account_info(),
+            },
+            signer,
+        ),
+        creator_amount,
+    )?;
+
     match option {
-        VestingOption::Withdraw => {
-            // Creator gets all tokens
-            transfer_tokens(
-                &ctx.accounts.vesting_token_account,
-                &ctx.accounts.creator_token_account,
-                &ctx.accounts.creator,
-                claimable_amount,
-                &ctx.accounts.token_program,
-            )?;
-            
-            vesting.released = vesting.amount;
-        },
-        
+        VestingOption::Withdraw => {},
         VestingOption::Burn => {
-            // Burn 50%, creator gets 50%
-            let burn_amount = claimable_amount / 2;
-            let creator_amount = claimable_amount - burn_amount;
-            
-            // Burn tokens
-            burn_tokens(
-                &ctx.accounts.vesting_token_account,
-                burn_amount,
-                &ctx.accounts.token_program,
+            // Burn from the mint with the vesting PDA as authority
+            token::burn(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: ctx.accounts.mint.to_account_info(),
+                        from: ctx.accounts.vesting_token_account.to_account_info(),
+                        authority: ctx.accounts.vesting.to_account_info(),
+                    },
+                    signer,
+                ),
+                held_amount,
             )?;
-            
-            // Transfer remaining to creator
-            transfer_tokens(
-                &ctx.accounts.vesting_token_account,
-                &ctx.accounts.creator_token_account,
-                &ctx.accounts.creator,
-                creator_amount,
-                &ctx.accounts.token_program,
-            )?;
-            
-            vesting.released = vesting.amount;
+
+            let token_metadata = &mut ctx.accounts.token_metadata;
+            token_metadata.total_supply = token_metadata.total_supply
+                .checked_sub(held_amount)
+                .ok_or(CustomError::MathOverflow)?;
         },
-        
         VestingOption::Distribute => {
-            // Distribute 50% to holders, creator gets 50%
-            let distribute_amount = claimable_amount / 2;
-            let creator_amount = claimable_amount - distribute_amount;
-            
-            // Transfer to treasury for distribution to holders
-            transfer_tokens(
-                &ctx.accounts.vesting_token_account,
-                &ctx.accounts.treasury,
-                &ctx.accounts.creator,
-                distribute_amount,
-                &ctx.accounts.token_program,
-            )?;
-            
-            // Transfer remaining to creator
-            transfer_tokens(
-                &ctx.accounts.vesting_token_account,
-                &ctx.accounts.creator_token_account,
-                &ctx.accounts.creator,
-                creator_amount,
-                &ctx.accounts.token_program,
-            )?;
-            
-            vesting.released = vesting.amount;
+            // Held in the vesting vault until the holder snapshot root is posted
         },
     }
-    
-    Ok(())
-}
 
-fn transfer_tokens(
-    from: &Account<TokenAccount>,
-    to: &Account<TokenAccount>,
-    authority: &Signer,
-    amount: u64,
-    token_program: &Program<Token>,
-) -> Result<()> {
-    let cpi_accounts = Transfer {
-        from: from.to_account_info(),
-        to: to.to_account_info(),
-        authority: authority.to_account_info(),
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.distribution_choice = Some(option);
+    vesting.choice_made = true;
+    vesting.released = if option == VestingOption::Distribute {
+        vesting.amount - held_amount
+    } else {
+        vesting.amount
     };
-    
-    let cpi_program = token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-    token::transfer(cpi_ctx, amount)?;
-    Ok(())
-}
 
-fn burn_tokens(
-    token_account: &Account<TokenAccount>,
-    amount: u64,
-    token_program: &Program<Token>,
-) -> Result<()> {
-    let cpi_accounts = Burn {
-        mint: token_account.to_account_info(),
-        from: token_account.to_account_info(),
-        authority: token_account.to_account_info(),
-    };
-    
-    let cpi_program = token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-    token::burn(cpi_ctx, amount)?;
+    msg!(
+        "Vesting option chosen: {} tokens to creator, {} tokens {}",
+        creator_amount,
+        held_amount,
+        match option {
+            VestingOption::Withdraw => "retained",
+            VestingOption::Burn => "burned",
+            VestingOption::Distribute => "reserved for holders",
+        }
+    );
+
     Ok(())
 }
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            owner.key().as_ref(),
+            &[staking_position.tier as u8]
+        ],
+        bump = staking_position.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub staking_position: Account<'info, StakingPosition>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = owner
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.update_rewards(current_time).ok_or(CustomError::MathOverflow)?;
+
+    let staking_position = &mut ctx.accounts.staking_position;
+    staking_position.settle_rewards(staking_pool).ok_or(CustomError::MathOverflow)?;
+
+    let amount = staking_position.pending_rewards;
+    require!(amount > 0, CustomError::NoRewardsToClaim);
+
+    let mint_key = staking_pool.mint;
+    let seeds = &[b"staking_pool".as_ref(), mint_key.as_ref(), &[staking_pool.bump]];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.staking_pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    let staking_position = &mut ctx.accounts.staking_position;
+    staking_position.pending_rewards = 0;
+    staking_position.rewards_claimed = staking_position.rewards_claimed
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(RewardsClaimed {
+        pool: ctx.accounts.staking_pool.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+        timestamp: current_time,
+    });
+
+    msg!("Claimed {} reward tokens", amount);
+
+    Ok(())
+}
@@ -2,20 +2,50 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 use crate::state::*;
 use crate::errors::CustomError;
+use crate::fair_voting_utils::FairVotingUtils;
+use crate::instructions::vesting_position::authorize_holder;
 
 #[derive(Accounts)]
 pub struct ClaimVested<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting.mint.as_ref(), vesting.beneficiary.as_ref()],
+        bump = vesting.bump
+    )]
     pub vesting: Account<'info, Vesting>,
     
     pub creator: Signer<'info>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        token::mint = vesting.mint,
+        token::authority = creator
+    )]
     pub creator_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        token::mint = vesting.mint,
+        token::authority = vesting
+    )]
     pub vesting_token_account: Account<'info, TokenAccount>,
     
+    #[account(
+        seeds = [b"token_metadata", vesting.mint.as_ref()],
+        bump = token_metadata.bump
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+    
+    /// Latest performance assessment of the token's creator; without it the minimum release applies
+    #[account(
+        seeds = [b"creator_performance", token_metadata.creator.as_ref(), vesting.mint.as_ref()],
+        bump
+    )]
+    pub creator_performance: Option<Account<'info, CreatorPerformance>>,
+    
+    /// Claimant's position NFT account; required once the vesting is tokenized
+    pub position_account: Option<Account<'info, TokenAccount>>,
+    
     pub token_program: Program<'info, Token>,
 }
 
@@ -23,14 +53,11 @@ pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
     let clock = Clock::get()?;
     let vesting = &mut ctx.accounts.vesting;
 
-    // Verify creator owns the vesting
-    require!(
-        vesting.owner == ctx.accounts.creator.key(),
-        CustomError::Unauthorized
-    );
+    // Verify the claimant owns the vesting and, once tokenized, holds its position NFT
+    authorize_holder(vesting, ctx.accounts.creator.key(), ctx.accounts.position_account.as_deref())?;
 
-    // Check if vesting is revoked
-    require!(!vesting.revoked, CustomError::VestingRevoked);
+    // A post-vesting choice settles the remainder
+    require!(!vesting.choice_made, CustomError::ChoiceAlreadyMade);
 
     // Check if cliff time has been reached
     require!(
@@ -38,27 +65,41 @@ pub fn handler(ctx: Context<ClaimVested>) -> Result<()> {
         CustomError::NotVested
     );
 
-    // Calculate claimable amount
-    let total_vested = if clock.unix_timestamp >= vesting.end_time {
-        vesting.amount
+    // Calculate claimable amount under the vesting schedule
+    let total_vested = vesting.vested_amount(clock.unix_timestamp);
+
+    // Until the final deadline, cap the unlock by the creator's performance
+    let final_deadline = vesting.end_time + PERFORMANCE_RELEASE_DEADLINE;
+    let total_vested = if clock.unix_timestamp >= final_deadline {
+        total_vested
     } else {
-        let time_elapsed = clock.unix_timestamp - vesting.start_time;
-        let total_duration = vesting.end_time - vesting.start_time;
-        (vesting.amount as u128 * time_elapsed as u128 / total_duration as u128) as u64
+        let performance_score = ctx.accounts.creator_performance
+            .as_ref()
+            .map(|performance| std::cmp::min(performance.performance_score, 100))
+            .unwrap_or(0);
+        let release_percentage = FairVotingUtils::determine_creator_release_percentage(performance_score)?;
+        (total_vested as u128 * release_percentage as u128 / 100) as u64
     };
 
-    let claimable = total_vested - vesting.released;
+    let claimable = total_vested.saturating_sub(vesting.released);
     require!(claimable > 0, CustomError::NothingToClaim);
 
-    // Transfer claimable tokens to creator's wallet
-    let seeds: &[&[&[u8]]] = &[];
+    // Transfer claimable tokens to creator's wallet, signed by the vesting PDA
+    let mint_key = vesting.mint;
+    let beneficiary_key = vesting.beneficiary;
+    let seeds: &[&[&[u8]]] = &[&[
+        b"vesting",
+        mint_key.as_ref(),
+        beneficiary_key.as_ref(),
+        &[vesting.bump],
+    ]];
     token::transfer(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             token::Transfer {
                 from: ctx.accounts.vesting_token_account.to_account_info(),
                 to: ctx.accounts.creator_token_account.to_account_info(),
-                authority: ctx.accounts.vesting.to_account_info(),
+                authority: vesting.to_account_info(),
             },
             seeds,
         ),
@@ -1,67 +1,117 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_lang::system_program;
 use crate::state::*;
+use crate::errors::CustomError;
 
 #[derive(Accounts)]
 pub struct CollectTradingFee<'info> {
     #[account(mut)]
     pub trader: Signer<'info>,
-    
-    #[account(mut)]
-    pub trader_token_account: Account<'info, TokenAccount>,
-    
-    #[account(mut)]
+
+    #[account(
+        mut,
+        constraint = treasury.authority == platform_config.authority @ CustomError::InvalidAccount
+    )]
     pub treasury: Account<'info, PlatformTreasury>,
-    
+
     #[account(mut)]
     pub staking_rewards: Account<'info, StakingRewards>,
-    
+
     /// Platform configuration for dynamic fee
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
     pub platform_config: Account<'info, PlatformConfig>,
-    
-    /// The token being traded
-    pub token_mint: Account<'info, Mint>,
-    
-    pub token_program: Program<'info, Token>,
+
+    /// Staker share of the fee is indexed here
+    #[account(
+        mut,
+        seeds = [b"fee_revenue_pool"],
+        bump = fee_revenue_pool.bump,
+        has_one = staking_pool @ CustomError::InvalidAccount
+    )]
+    pub fee_revenue_pool: Account<'info, FeeRevenuePool>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_revenue_vault"],
+        bump = fee_revenue_pool.vault_bump
+    )]
+    pub fee_revenue_vault: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<CollectTradingFee>, trade_amount: u64) -> Result<()> {
     let platform_config = &ctx.accounts.platform_config;
-    
-    // Use dynamic trading fee from platform config
-    let trading_fee = (trade_amount * platform_config.trading_fee_percentage as u64) / 1000; // Basis points
-    
-    // Transfer trading fee to treasury
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.trader_token_account.to_account_info(),
-        to: ctx.accounts.treasury.to_account_info(),
-        authority: ctx.accounts.trader.to_account_info(),
-    };
-    
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    
-    token::transfer(cpi_ctx, trading_fee)?;
-    
+
+    // Use dynamic trading fee from platform config (SOL, per-mille of the trade)
+    let trading_fee = trade_amount
+        .checked_mul(platform_config.trading_fee_percentage as u64)
+        .ok_or(CustomError::MathOverflow)?
+        / 1000;
+
+    // Calculate distribution (55% to stakers, 35% to development, 10% to governance)
+    let staker_portion = (trading_fee * STAKER_REWARD_PERCENTAGE as u64) / 100;
+    let development_portion = (trading_fee * DEVELOPMENT_PERCENTAGE as u64) / 100;
+    let governance_portion = (trading_fee * GOVERNANCE_PERCENTAGE as u64) / 100;
+    let treasury_portion = trading_fee - staker_portion;
+
+    // Staker share goes to the fee revenue vault, the rest to the treasury
+    if staker_portion > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.trader.to_account_info(),
+                    to: ctx.accounts.fee_revenue_vault.to_account_info(),
+                },
+            ),
+            staker_portion,
+        )?;
+    }
+    if treasury_portion > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.trader.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            treasury_portion,
+        )?;
+    }
+
+    // Queue the staker share; it streams into the fee index over the next epoch
+    let current_time = Clock::get()?.unix_timestamp;
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.update_rewards(current_time).ok_or(CustomError::MathOverflow)?;
+    ctx.accounts.fee_revenue_pool
+        .deposit(staking_pool, staker_portion, current_time)
+        .ok_or(CustomError::MathOverflow)?;
+
     // Update treasury stats
     let treasury = &mut ctx.accounts.treasury;
     treasury.fee_collection_stats.total_trading_fees += trading_fee;
-    treasury.last_updated = Clock::get()?.unix_timestamp;
-    
+    treasury.last_updated = current_time;
+
     // Update staking rewards pool
     let staking_rewards = &mut ctx.accounts.staking_rewards;
     staking_rewards.platform_fees_collected += trading_fee;
-    
-    // Calculate distribution (55% to stakers, 35% to development, 10% to governance)
-    let staker_portion = (trading_fee * STAKER_REWARD_PERCENTAGE as u64) / 100;
-    let development_portion = (trading_fee * DEVELOPMENT_PERCENTAGE as u64) / 100;
-    let governance_portion = (trading_fee * GOVERNANCE_PERCENTAGE as u64) / 100;
-    
+
     staking_rewards.total_rewards_distributed += staker_portion;
     treasury.fee_collection_stats.fees_distributed_to_stakers += staker_portion;
     treasury.fee_collection_stats.fees_retained_for_development += development_portion;
     treasury.fee_collection_stats.fees_for_governance += governance_portion;
-    
+
     Ok(())
 }
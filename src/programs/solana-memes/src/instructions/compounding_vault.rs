@@ -0,0 +1,591 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::phase_1_3_structures::AutoCompoundingFrequency;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeCompoundingVault<'info> {
+    #[account(
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CompoundingVault::LEN,
+        seeds = [b"compounding_vault", staking_pool.key().as_ref()],
+        bump
+    )]
+    pub compounding_vault: Account<'info, CompoundingVault>,
+
+    /// The vault stakes through an ordinary flexible position it owns; it earns staking
+    /// rewards but sits outside the SOL fee index, which it could never claim
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StakingPosition::LEN,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            compounding_vault.key().as_ref(),
+            &[StakeTier::Flexible as u8]
+        ],
+        bump
+    )]
+    pub vault_position: Account<'info, StakingPosition>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositToVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"compounding_vault", staking_pool.key().as_ref()],
+        bump = compounding_vault.bump
+    )]
+    pub compounding_vault: Account<'info, CompoundingVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            compounding_vault.key().as_ref(),
+            &[StakeTier::Flexible as u8]
+        ],
+        bump = vault_position.bump
+    )]
+    pub vault_position: Account<'info, StakingPosition>,
+
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + VaultDeposit::LEN,
+        seeds = [b"vault_deposit", compounding_vault.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub vault_deposit: Account<'info, VaultDeposit>,
+
+    #[account(
+        seeds = [b"staking_tiers"],
+        bump = staking_tier_config.bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.stake_vault_bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"compounding_vault", staking_pool.key().as_ref()],
+        bump = compounding_vault.bump
+    )]
+    pub compounding_vault: Account<'info, CompoundingVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            compounding_vault.key().as_ref(),
+            &[StakeTier::Flexible as u8]
+        ],
+        bump = vault_position.bump
+    )]
+    pub vault_position: Account<'info, StakingPosition>,
+
+    #[account(
+        mut,
+        seeds = [b"vault_deposit", compounding_vault.key().as_ref(), user.key().as_ref()],
+        bump = vault_deposit.bump,
+        constraint = vault_deposit.owner == user.key() @ CustomError::Unauthorized
+    )]
+    pub vault_deposit: Account<'info, VaultDeposit>,
+
+    #[account(
+        seeds = [b"staking_tiers"],
+        bump = staking_tier_config.bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.stake_vault_bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CompoundVault<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"compounding_vault", staking_pool.key().as_ref()],
+        bump = compounding_vault.bump
+    )]
+    pub compounding_vault: Account<'info, CompoundingVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            compounding_vault.key().as_ref(),
+            &[StakeTier::Flexible as u8]
+        ],
+        bump = vault_position.bump
+    )]
+    pub vault_position: Account<'info, StakingPosition>,
+
+    #[account(
+        seeds = [b"staking_tiers"],
+        bump = staking_tier_config.bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.stake_vault_bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = cranker
+    )]
+    pub cranker_token_account: Account<'info, TokenAccount>,
+
+    /// Anyone can crank once the interval has passed
+    pub cranker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct VaultCompounded {
+    pub vault: Pubkey,
+    pub restaked: u64,
+    pub crank_incentive: u64,
+    pub cranker: Pubkey,
+    pub total_assets: u64,
+    pub total_shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultDepositChanged {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub is_deposit: bool,
+    pub timestamp: i64,
+}
+
+pub fn initialize_compounding_vault(
+    ctx: Context<InitializeCompoundingVault>,
+    frequency: AutoCompoundingFrequency,
+    custom_interval: Option<i64>,
+    crank_incentive_bps: u16,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let compound_interval = match frequency.interval_seconds() {
+        Some(interval) => interval,
+        None => custom_interval.ok_or(CustomError::InvalidCompoundingVault)?,
+    };
+    require!(compound_interval > 0, CustomError::InvalidCompoundingVault);
+    require!(
+        crank_incentive_bps <= MAX_CRANK_INCENTIVE_BPS,
+        CustomError::InvalidCompoundingVault
+    );
+
+    let compounding_vault = &mut ctx.accounts.compounding_vault;
+    compounding_vault.pool = ctx.accounts.staking_pool.key();
+    compounding_vault.total_shares = 0;
+    compounding_vault.compound_interval = compound_interval;
+    compounding_vault.last_compound_time = current_time;
+    compounding_vault.crank_incentive_bps = crank_incentive_bps;
+    compounding_vault.total_compounded = 0;
+    compounding_vault.created_at = current_time;
+    compounding_vault.bump = *ctx.bumps.get("compounding_vault").unwrap();
+    compounding_vault.receipt_mint = None;
+
+    let vault_position = &mut ctx.accounts.vault_position;
+    vault_position.owner = compounding_vault.key();
+    vault_position.pool = compounding_vault.pool;
+    vault_position.amount = 0;
+    vault_position.start_time = current_time;
+    vault_position.end_time = current_time;
+    vault_position.rewards_claimed = 0;
+    vault_position.pending_rewards = 0;
+    vault_position.is_locked = false;
+    vault_position.reward_per_token_paid = ctx.accounts.staking_pool.reward_per_token_stored;
+    vault_position.bump = *ctx.bumps.get("vault_position").unwrap();
+    vault_position.tier = StakeTier::Flexible;
+    vault_position.weighted_amount = 0;
+    vault_position.fee_revenue_per_token_paid = ctx.accounts.staking_pool.fee_revenue_per_token_stored;
+    vault_position.pending_fee_revenue = 0;
+    vault_position.fee_exempt = true;
+    vault_position.vote_locked_until = 0;
+    vault_position.last_deposit_time = current_time;
+
+    msg!(
+        "Compounding vault created for pool {}: every {}s, {} bps crank incentive",
+        compounding_vault.pool,
+        compound_interval,
+        crank_incentive_bps
+    );
+
+    Ok(())
+}
+
+pub fn deposit_to_vault(ctx: Context<DepositToVault>, amount: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.staking_pool.is_active, CustomError::StakingPoolInactive);
+    require!(
+        amount >= ctx.accounts.staking_pool.min_stake_amount,
+        CustomError::InsufficientStakeAmount
+    );
+
+    // Fold accrued rewards in first so new shares are priced fairly
+    let multiplier_bps = ctx.accounts.staking_tier_config.multiplier_for(StakeTier::Flexible);
+    compound_position(
+        &mut ctx.accounts.staking_pool,
+        &mut ctx.accounts.vault_position,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.token_program,
+        multiplier_bps,
+        current_time,
+        0,
+    )?;
+
+    let assets = ctx.accounts.vault_position.amount;
+    require!(
+        assets > 0 || ctx.accounts.compounding_vault.total_shares == 0,
+        CustomError::VaultHasNoAssets
+    );
+    let shares = ctx.accounts.compounding_vault
+        .shares_for_deposit(amount, assets)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(shares > 0, CustomError::InvalidAmount);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let new_amount = assets.checked_add(amount).ok_or(CustomError::MathOverflow)?;
+    ctx.accounts.vault_position
+        .set_amount(&mut ctx.accounts.staking_pool, new_amount, multiplier_bps)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let compounding_vault = &mut ctx.accounts.compounding_vault;
+    compounding_vault.total_shares = compounding_vault.total_shares
+        .checked_add(shares)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let vault_deposit = &mut ctx.accounts.vault_deposit;
+    if vault_deposit.owner == Pubkey::default() {
+        vault_deposit.vault = compounding_vault.key();
+        vault_deposit.owner = ctx.accounts.user.key();
+        vault_deposit.shares = 0;
+        vault_deposit.bump = *ctx.bumps.get("vault_deposit").unwrap();
+    }
+    vault_deposit.shares = vault_deposit.shares
+        .checked_add(shares)
+        .ok_or(CustomError::MathOverflow)?;
+    vault_deposit.deposited_at = current_time;
+
+    emit!(VaultDepositChanged {
+        vault: compounding_vault.key(),
+        owner: vault_deposit.owner,
+        amount,
+        shares,
+        is_deposit: true,
+        timestamp: current_time,
+    });
+
+    msg!("Deposited {} tokens into compounding vault for {} shares", amount, shares);
+
+    Ok(())
+}
+
+pub fn withdraw_from_vault(ctx: Context<WithdrawFromVault>, shares: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(shares > 0, CustomError::InvalidAmount);
+    require!(shares <= ctx.accounts.vault_deposit.shares, CustomError::InsufficientVaultShares);
+
+    let multiplier_bps = ctx.accounts.staking_tier_config.multiplier_for(StakeTier::Flexible);
+    compound_position(
+        &mut ctx.accounts.staking_pool,
+        &mut ctx.accounts.vault_position,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.token_program,
+        multiplier_bps,
+        current_time,
+        0,
+    )?;
+
+    let assets = ctx.accounts.vault_position.amount;
+    let amount = ctx.accounts.compounding_vault
+        .assets_for_shares(shares, assets)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(amount > 0, CustomError::InvalidAmount);
+
+    ctx.accounts.vault_position
+        .set_amount(&mut ctx.accounts.staking_pool, assets - amount, multiplier_bps)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let mint_key = ctx.accounts.staking_pool.mint;
+    let seeds = &[b"staking_pool".as_ref(), mint_key.as_ref(), &[ctx.accounts.staking_pool.bump]];
+    let signer = &[&seeds[..]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.staking_pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    let compounding_vault = &mut ctx.accounts.compounding_vault;
+    compounding_vault.total_shares -= shares;
+    let vault_deposit = &mut ctx.accounts.vault_deposit;
+    vault_deposit.shares -= shares;
+
+    emit!(VaultDepositChanged {
+        vault: compounding_vault.key(),
+        owner: vault_deposit.owner,
+        amount,
+        shares,
+        is_deposit: false,
+        timestamp: current_time,
+    });
+
+    // Refund rent once every share is redeemed
+    if vault_deposit.shares == 0 {
+        ctx.accounts.vault_deposit.close(ctx.accounts.user.to_account_info())?;
+    }
+
+    msg!("Withdrew {} tokens from compounding vault for {} shares", amount, shares);
+
+    Ok(())
+}
+
+pub fn compound_vault(ctx: Context<CompoundVault>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let compounding_vault = &ctx.accounts.compounding_vault;
+
+    require!(
+        current_time >= compounding_vault.last_compound_time + compounding_vault.compound_interval,
+        CustomError::CompoundTooEarly
+    );
+
+    let multiplier_bps = ctx.accounts.staking_tier_config.multiplier_for(StakeTier::Flexible);
+    let (restaked, crank_incentive) = compound_position(
+        &mut ctx.accounts.staking_pool,
+        &mut ctx.accounts.vault_position,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.token_program,
+        multiplier_bps,
+        current_time,
+        compounding_vault.crank_incentive_bps,
+    )?;
+    require!(restaked > 0, CustomError::NoRewardsToClaim);
+
+    // Pay the cranker out of the compounded rewards
+    if crank_incentive > 0 {
+        let mint_key = ctx.accounts.staking_pool.mint;
+        let seeds = &[b"staking_pool".as_ref(), mint_key.as_ref(), &[ctx.accounts.staking_pool.bump]];
+        let signer = &[&seeds[..]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.cranker_token_account.to_account_info(),
+                    authority: ctx.accounts.staking_pool.to_account_info(),
+                },
+                signer,
+            ),
+            crank_incentive,
+        )?;
+    }
+
+    let compounding_vault = &mut ctx.accounts.compounding_vault;
+    compounding_vault.last_compound_time = current_time;
+    compounding_vault.total_compounded = compounding_vault.total_compounded
+        .checked_add(restaked)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(VaultCompounded {
+        vault: compounding_vault.key(),
+        restaked,
+        crank_incentive,
+        cranker: ctx.accounts.cranker.key(),
+        total_assets: ctx.accounts.vault_position.amount,
+        total_shares: compounding_vault.total_shares,
+        timestamp: current_time,
+    });
+
+    msg!("Compounded {} tokens; cranker paid {}", restaked, crank_incentive);
+
+    Ok(())
+}
+
+/// Settle the vault position and restake its pending rewards, holding back the crank incentive
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn compound_position<'info>(
+    staking_pool: &mut Account<'info, StakingPool>,
+    vault_position: &mut Account<'info, StakingPosition>,
+    reward_vault: &Account<'info, TokenAccount>,
+    stake_vault: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    multiplier_bps: u16,
+    current_time: i64,
+    incentive_bps: u16,
+) -> Result<(u64, u64)> {
+    staking_pool.update_rewards(current_time).ok_or(CustomError::MathOverflow)?;
+    vault_position.settle_rewards(staking_pool).ok_or(CustomError::MathOverflow)?;
+
+    let pending = vault_position.pending_rewards;
+    if pending == 0 {
+        return Ok((0, 0));
+    }
+    let incentive = (pending as u128 * incentive_bps as u128 / 10_000) as u64;
+    let restaked = pending - incentive;
+
+    let mint_key = staking_pool.mint;
+    let seeds = &[b"staking_pool".as_ref(), mint_key.as_ref(), &[staking_pool.bump]];
+    let signer = &[&seeds[..]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: reward_vault.to_account_info(),
+                to: stake_vault.to_account_info(),
+                authority: staking_pool.to_account_info(),
+            },
+            signer,
+        ),
+        restaked,
+    )?;
+
+    let new_amount = vault_position.amount
+        .checked_add(restaked)
+        .ok_or(CustomError::MathOverflow)?;
+    vault_position
+        .set_amount(staking_pool, new_amount, multiplier_bps)
+        .ok_or(CustomError::MathOverflow)?;
+    vault_position.pending_rewards = 0;
+    vault_position.rewards_claimed = vault_position.rewards_claimed
+        .checked_add(pending)
+        .ok_or(CustomError::MathOverflow)?;
+
+    Ok((restaked, incentive))
+}
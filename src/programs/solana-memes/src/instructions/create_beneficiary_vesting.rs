@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+#[instruction(index: u8)]
+pub struct CreateBeneficiaryVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"creator_allocation", mint.key().as_ref()],
+        bump = creator_allocation.bump,
+        has_one = mint
+    )]
+    pub creator_allocation: Account<'info, CreatorAllocation>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator_allocation
+    )]
+    pub allocation_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: Must match the beneficiary wallet at `index`
+    #[account(
+        constraint = creator_allocation.beneficiaries
+            .get(index as usize)
+            .map(|b| b.wallet == beneficiary.key())
+            .unwrap_or(false) @ CustomError::InvalidBeneficiaries
+    )]
+    pub beneficiary: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Vesting::LEN,
+        seeds = [b"vesting", mint.key().as_ref(), beneficiary.key().as_ref()],
+        bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    /// Deterministic ATA that anyone could create first, so it is reused if it exists
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = vesting
+    )]
+    pub vesting_token_account: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handler(ctx: Context<CreateBeneficiaryVesting>, index: u8) -> Result<()> {
+    let creator_allocation = &ctx.accounts.creator_allocation;
+    let allocation = creator_allocation.beneficiaries[index as usize].clone();
+    require!(!allocation.vesting_created, CustomError::BeneficiaryVestingExists);
+
+    // Move the beneficiary's share out of escrow, signed by the allocation PDA
+    let mint_key = ctx.accounts.mint.key();
+    let seeds: &[&[&[u8]]] = &[&[
+        b"creator_allocation",
+        mint_key.as_ref(),
+        &[creator_allocation.bump],
+    ]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.allocation_vault.to_account_info(),
+                to: ctx.accounts.vesting_token_account.to_account_info(),
+                authority: creator_allocation.to_account_info(),
+            },
+            seeds,
+        ),
+        allocation.amount,
+    )?;
+
+    // Vesting runs from launch, not from when this account is created
+    let start_time = creator_allocation.created_at;
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.owner = allocation.wallet;
+    vesting.beneficiary = allocation.wallet;
+    vesting.mint = mint_key;
+    vesting.amount = allocation.amount;
+    vesting.start_time = start_time;
+    vesting.cliff_time = start_time + allocation.cliff_seconds;
+    vesting.end_time = start_time + allocation.vesting_seconds;
+    vesting.released = 0;
+    vesting.is_revocable = true; // Creator allocations can be revoked by governance
+    vesting.revoked = false;
+    vesting.revoke_time = None;
+    vesting.distribution_choice = None;
+    vesting.choice_deadline = vesting.end_time + PERFORMANCE_RELEASE_DEADLINE + VESTING_CHOICE_WINDOW;
+    vesting.choice_made = false;
+    vesting.bump = *ctx.bumps.get("vesting").unwrap();
+    vesting.schedule = allocation.schedule;
+    vesting.position_mint = None;
+    vesting.last_transfer_time = start_time;
+
+    ctx.accounts.creator_allocation.beneficiaries[index as usize].vesting_created = true;
+
+    msg!(
+        "Vesting created for beneficiary {}: {} tokens, cliff {}s, duration {}s",
+        allocation.wallet,
+        allocation.amount,
+        allocation.cliff_seconds,
+        allocation.vesting_seconds
+    );
+
+    Ok(())
+}
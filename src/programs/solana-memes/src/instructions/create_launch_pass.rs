@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, SetAuthority, FreezeAccount};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::associated_token::AssociatedToken;
+use mpl_token_metadata::instructions::CreateMetadataAccountV3CpiBuilder;
+use mpl_token_metadata::types::DataV2;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct CreateLaunchPass<'info> {
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Wallet receiving the launch pass
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 0,
+        mint::authority = launch_pass,
+        mint::freeze_authority = launch_pass
+    )]
+    pub pass_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LaunchPass::LEN,
+        seeds = [b"launch_pass", pass_mint.key().as_ref()],
+        bump
+    )]
+    pub launch_pass: Account<'info, LaunchPass>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = pass_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex metadata PDA, initialized by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), pass_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metaplex_metadata: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID @ CustomError::InvalidMetadataProgram)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct BindLaunchPass<'info> {
+    #[account(
+        mut,
+        seeds = [b"creator_profile", creator.key().as_ref()],
+        bump = creator_profile.bump,
+        constraint = creator_profile.owner == creator.key() @ CustomError::Unauthorized
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"launch_pass", launch_pass.mint.as_ref()],
+        bump = launch_pass.bump
+    )]
+    pub launch_pass: Account<'info, LaunchPass>,
+
+    /// Creator's token account holding the launch pass NFT
+    pub launch_pass_token_account: Account<'info, TokenAccount>,
+}
+
+pub fn handler(
+    ctx: Context<CreateLaunchPass>,
+    name: String,
+    symbol: String,
+    uri: String,
+    expires_at: Option<i64>,
+    transferable: bool,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(name.len() <= mpl_token_metadata::MAX_NAME_LENGTH, CustomError::InvalidMetadataName);
+    require!(symbol.len() <= mpl_token_metadata::MAX_SYMBOL_LENGTH, CustomError::InvalidMetadataSymbol);
+    require!(uri.len() <= mpl_token_metadata::MAX_URI_LENGTH, CustomError::InvalidMetadataUri);
+
+    if let Some(expires_at) = expires_at {
+        require!(expires_at > clock.unix_timestamp, CustomError::InvalidTimestamp);
+    }
+
+    let pass_mint_key = ctx.accounts.pass_mint.key();
+    let bump = *ctx.bumps.get("launch_pass").unwrap();
+    let seeds = &[b"launch_pass".as_ref(), pass_mint_key.as_ref(), &[bump]];
+    let signer = &[&seeds[..]];
+
+    // Create the Metaplex metadata so the pass shows up as an NFT in wallets
+    CreateMetadataAccountV3CpiBuilder::new(&ctx.accounts.token_metadata_program.to_account_info())
+        .metadata(&ctx.accounts.metaplex_metadata.to_account_info())
+        .mint(&ctx.accounts.pass_mint.to_account_info())
+        .mint_authority(&ctx.accounts.launch_pass.to_account_info())
+        .payer(&ctx.accounts.authority.to_account_info())
+        .update_authority(&ctx.accounts.authority.to_account_info(), true)
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .rent(Some(&ctx.accounts.rent.to_account_info()))
+        .data(DataV2 {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(false)
+        .invoke_signed(signer)?;
+
+    // Mint exactly one pass to the recipient
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.pass_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.launch_pass.to_account_info(),
+            },
+            signer,
+        ),
+        1,
+    )?;
+
+    // Lock the supply at one
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.launch_pass.to_account_info(),
+                account_or_mint: ctx.accounts.pass_mint.to_account_info(),
+            },
+            signer,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    // Soulbound passes are frozen so they can never leave the recipient's wallet
+    if !transferable {
+        token::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.recipient_token_account.to_account_info(),
+                mint: ctx.accounts.pass_mint.to_account_info(),
+                authority: ctx.accounts.launch_pass.to_account_info(),
+            },
+            signer,
+        ))?;
+    }
+
+    let launch_pass = &mut ctx.accounts.launch_pass;
+    launch_pass.mint = pass_mint_key;
+    launch_pass.owner = ctx.accounts.recipient.key();
+    launch_pass.creator = ctx.accounts.authority.key();
+    launch_pass.name = name;
+    launch_pass.symbol = symbol;
+    launch_pass.uri = uri;
+    launch_pass.is_used = false;
+    launch_pass.used_for_token = None;
+    launch_pass.created_at = clock.unix_timestamp;
+    launch_pass.expires_at = expires_at;
+    launch_pass.transferable = transferable;
+    launch_pass.bump = bump;
+
+    msg!("Launch pass {} issued to {}", pass_mint_key, launch_pass.owner);
+    msg!("Transferable: {}, Expires at: {:?}", transferable, expires_at);
+
+    Ok(())
+}
+
+pub fn bind_launch_pass(ctx: Context<BindLaunchPass>) -> Result<()> {
+    let launch_pass = &ctx.accounts.launch_pass;
+    let pass_token_account = &ctx.accounts.launch_pass_token_account;
+    let clock = Clock::get()?;
+
+    require!(ctx.accounts.creator_profile.is_registered, CustomError::NotRegistered);
+    require!(pass_token_account.mint == launch_pass.mint, CustomError::InvalidLaunchPass);
+    require!(
+        pass_token_account.owner == ctx.accounts.creator.key() && pass_token_account.amount == 1,
+        CustomError::LaunchPassNotOwned
+    );
+    require!(!launch_pass.is_used, CustomError::LaunchPassUsed);
+    if let Some(expires_at) = launch_pass.expires_at {
+        require!(clock.unix_timestamp < expires_at, CustomError::LaunchPassExpired);
+    }
+
+    let creator = &mut ctx.accounts.creator_profile;
+    creator.launch_pass_mint = Some(launch_pass.mint);
+
+    msg!("Launch pass {} bound to creator {}", launch_pass.mint, creator.owner);
+
+    Ok(())
+}
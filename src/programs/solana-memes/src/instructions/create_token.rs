@@ -1,21 +1,53 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, InitializeMint};
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo, SetAuthority};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
 use anchor_spl::associated_token::AssociatedToken;
+use mpl_token_metadata::instructions::CreateMetadataAccountV3CpiBuilder;
+use mpl_token_metadata::types::DataV2;
 use crate::state::*;
 use crate::errors::CustomError;
 
 #[derive(Accounts)]
+#[instruction(
+    name: String,
+    symbol: String,
+    uri: String,
+    decimals: u8,
+    total_supply: u64,
+    creator_percent: u8,
+    beneficiaries: Vec<VestingBeneficiary>,
+    immutable_metadata: bool,
+    fixed_supply: Option<bool>,
+    normalized_symbol: String,
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    lp_lock_seconds: i64
+)]
 pub struct CreateToken<'info> {
-    #[account(mut, has_one = owner)]
+    #[account(
+        mut,
+        seeds = [b"creator_profile", creator.key().as_ref()],
+        bump = creator_profile.bump,
+        constraint = creator_profile.owner == creator.key() @ CustomError::Unauthorized
+    )]
     pub creator_profile: Account<'info, CreatorProfile>,
     
+    #[account(
+        seeds = [b"creator_policy"],
+        bump = creator_policy.bump
+    )]
+    pub creator_policy: Account<'info, CreatorPolicy>,
+    
+    #[account(mut)]
     pub creator: Signer<'info>,
     
     #[account(
         init,
         payer = creator,
-        mint::decimals = 9,
-        mint::authority = creator
+        mint::decimals = decimals,
+        mint::authority = creator,
+        mint::freeze_authority = creator
     )]
     pub mint: Account<'info, Mint>,
     
@@ -31,50 +63,114 @@ pub struct CreateToken<'info> {
         init,
         payer = creator,
         associated_token::mint = mint,
-        associated_token::authority = vesting
+        associated_token::authority = creator_allocation
     )]
-    pub vesting_token_account: Account<'info, TokenAccount>,
+    pub allocation_vault: Account<'info, TokenAccount>,
     
     #[account(
         init,
         payer = creator,
-        space = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 8
+        space = 8 + CreatorAllocation::LEN,
+        seeds = [b"creator_allocation", mint.key().as_ref()],
+        bump
     )]
-    pub vesting: Account<'info, Vesting>,
+    pub creator_allocation: Account<'info, CreatorAllocation>,
     
     #[account(
         init,
         payer = creator,
-        space = 8 + 32 + 32 + 200 + 200 + 200 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8 + 8
+        space = 8 + TokenMetadata::LEN,
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump
     )]
     pub token_metadata: Account<'info, TokenMetadata>,
     
     #[account(
         init,
         payer = creator,
-        space = 8 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8
+        space = 8 + StakingPool::LEN,
+        seeds = [b"staking_pool", mint.key().as_ref()],
+        bump
     )]
     pub staking_pool: Account<'info, StakingPool>,
     
     #[account(
         init,
         payer = creator,
-        space = 8 + 32 + 1 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8
+        space = 8 + AntiBotConfig::LEN,
+        seeds = [b"anti_bot_config", mint.key().as_ref()],
+        bump
     )]
     pub anti_bot_config: Account<'info, AntiBotConfig>,
     
     #[account(
         init,
         payer = creator,
-        space = 8 + 32 + 8 + 8 + 8 + 8 + 16 + 1 + 8 + 8 + 8
+        space = 8 + LiquidityPool::LEN,
+        seeds = [b"liquidity_pool", mint.key().as_ref()],
+        bump
     )]
     pub liquidity_pool: Account<'info, LiquidityPool>,
     
-    /// CHECK: Launch pass verification
-    pub launch_pass: Option<AccountInfo<'info>>,
+    /// The bonding curve is the primary market, so it opens with the public supply at launch
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + BondingCurve::LEN,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+    
+    #[account(
+        init,
+        payer = creator,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_vault: Account<'info, TokenAccount>,
+    
+    /// SOL vault holding curve reserves
+    #[account(
+        mut,
+        seeds = [b"bonding_curve_sol_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub curve_sol_vault: SystemAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + SymbolRegistry::LEN,
+        seeds = [b"symbol_registry", normalized_symbol.as_bytes()],
+        bump
+    )]
+    pub symbol_registry: Account<'info, SymbolRegistry>,
+    
+    /// CHECK: Metaplex metadata PDA, initialized by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metaplex_metadata: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"launch_pass", launch_pass.mint.as_ref()],
+        bump = launch_pass.bump
+    )]
+    pub launch_pass: Account<'info, LaunchPass>,
+    
+    /// Creator's token account holding the launch pass NFT
+    pub launch_pass_token_account: Account<'info, TokenAccount>,
     
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID @ CustomError::InvalidMetadataProgram)]
+    pub token_metadata_program: UncheckedAccount<'info>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -87,51 +183,164 @@ pub fn handler(
     decimals: u8,
     total_supply: u64,
     creator_percent: u8,
-    vesting_seconds: i64,
+    beneficiaries: Vec<VestingBeneficiary>,
+    immutable_metadata: bool,
+    fixed_supply: Option<bool>,
+    normalized_symbol: String,
+    virtual_sol_reserves: u64,
+    virtual_token_reserves: u64,
+    lp_lock_seconds: i64,
 ) -> Result<()> {
     let creator = &mut ctx.accounts.creator_profile;
     let clock = Clock::get()?;
+    let fixed_supply = fixed_supply.unwrap_or(true);
 
     // Ensure creator is registered
     require!(creator.is_registered, CustomError::NotRegistered);
     require!(!creator.is_banned, CustomError::CreatorBanned);
+    require!(creator.deregistration_requested_at.is_none(), CustomError::CreatorDeregistering);
 
-    // Enforce rate-limit: 1 token per 30 days
+    // Enforce the creator policy tier earned through reputation and track record
+    let tier = ctx.accounts.creator_policy
+        .tier_for(creator.reputation_score, creator.successful_tokens)
+        .ok_or(CustomError::CreatorBelowMinimumTier)?
+        .clone();
     require!(
-        clock.unix_timestamp - creator.last_creation_ts > RATE_LIMIT_PERIOD,
+        creator.last_creation_ts == 0
+            || clock.unix_timestamp - creator.last_creation_ts >= tier.creation_cooldown,
         CustomError::RateLimit
     );
+    if clock.unix_timestamp - creator.last_week_reset >= WEEK_IN_SECONDS {
+        creator.weekly_creation_count = 0;
+        creator.last_week_reset = clock.unix_timestamp;
+    }
+    require!(
+        creator.weekly_creation_count < tier.max_weekly_creations,
+        CustomError::WeeklyCreationLimitExceeded
+    );
+    creator.max_allocation_percent = tier.max_allocation_percent;
 
     // Validate creator percentage
     require!(creator_percent <= MAX_CREATOR_PERCENT, CustomError::InvalidCreatorPercent);
+    require!(
+        creator_percent <= MAX_CREATOR_ALLOCATION_PERCENT
+            && creator_percent <= creator.max_allocation_percent,
+        CustomError::AllocationExceedsMaximum
+    );
     require!(creator_percent > 0, CustomError::InvalidCreatorPercent);
 
-    // Validate vesting period
+    // Validate beneficiaries and their vesting periods
+    require!(
+        !beneficiaries.is_empty() && beneficiaries.len() <= CreatorAllocation::MAX_BENEFICIARIES,
+        CustomError::InvalidBeneficiaries
+    );
+    let mut total_share_bps: u32 = 0;
+    for (i, beneficiary) in beneficiaries.iter().enumerate() {
+        require!(beneficiary.share_bps > 0, CustomError::InvalidBeneficiaries);
+        require!(
+            beneficiaries[..i].iter().all(|other| other.wallet != beneficiary.wallet),
+            CustomError::InvalidBeneficiaries
+        );
+        require!(
+            beneficiary.schedule.is_valid(beneficiary.cliff_seconds, beneficiary.vesting_seconds),
+            CustomError::InvalidVestingPeriod
+        );
+        total_share_bps += beneficiary.share_bps as u32;
+    }
     require!(
-        vesting_seconds >= MIN_VESTING_PERIOD && vesting_seconds <= MAX_VESTING_PERIOD,
-        CustomError::InvalidVestingPeriod
+        total_share_bps == creator_percent as u32 * 100,
+        CustomError::InvalidBeneficiaries
     );
+    let vesting_seconds = beneficiaries
+        .iter()
+        .map(|beneficiary| beneficiary.vesting_seconds)
+        .max()
+        .unwrap_or(0);
 
     // Validate token parameters
     require!(total_supply > 0, CustomError::InvalidTokenSupply);
     require!(decimals <= 9, CustomError::InvalidTokenDecimals);
-    require!(name.len() <= 200, CustomError::TokenNameTooLong);
-    require!(symbol.len() <= 200, CustomError::TokenSymbolTooLong);
-    require!(uri.len() <= 200, CustomError::TokenUriTooLong);
-
-    // Check launch pass if required
-    if creator.launch_pass_required {
-        require!(ctx.accounts.launch_pass.is_some(), CustomError::LaunchPassRequired);
-        // In production, verify the launch pass NFT ownership and validity
+    require!(name.len() <= TokenMetadata::MAX_NAME_LEN, CustomError::TokenNameTooLong);
+    require!(symbol.len() <= TokenMetadata::MAX_SYMBOL_LEN, CustomError::TokenSymbolTooLong);
+    require!(uri.len() <= TokenMetadata::MAX_URI_LEN, CustomError::TokenUriTooLong);
+
+    // Validate curve parameters
+    require!(
+        virtual_sol_reserves >= MIN_VIRTUAL_SOL_RESERVES
+            && virtual_sol_reserves <= MAX_VIRTUAL_SOL_RESERVES,
+        CustomError::InvalidBondingCurve
+    );
+    require!(
+        lp_lock_seconds == 0 || lp_lock_seconds >= LIQUIDITY_LOCK_PERIOD,
+        CustomError::InvalidBondingCurve
+    );
+
+    // Metaplex enforces tighter limits than our own metadata account
+    require!(name.len() <= mpl_token_metadata::MAX_NAME_LENGTH, CustomError::InvalidMetadataName);
+    require!(symbol.len() <= mpl_token_metadata::MAX_SYMBOL_LENGTH, CustomError::InvalidMetadataSymbol);
+    require!(uri.len() <= mpl_token_metadata::MAX_URI_LENGTH, CustomError::InvalidMetadataUri);
+
+    // Claim the normalized ticker so look-alike symbols cannot be launched again
+    require!(
+        !normalized_symbol.is_empty() && normalized_symbol == SymbolRegistry::normalize(&symbol),
+        CustomError::InvalidSymbol
+    );
+    let symbol_registry = &mut ctx.accounts.symbol_registry;
+    require!(symbol_registry.mint == Pubkey::default(), CustomError::SymbolTaken);
+    require!(
+        symbol_registry.registered_by == Pubkey::default()
+            || symbol_registry.registered_by == ctx.accounts.creator.key()
+            || clock.unix_timestamp >= symbol_registry.reserved_until,
+        CustomError::SymbolReserved
+    );
+    symbol_registry.normalized_symbol = normalized_symbol;
+    symbol_registry.mint = ctx.accounts.mint.key();
+    symbol_registry.registered_by = ctx.accounts.creator.key();
+    symbol_registry.registered_at = clock.unix_timestamp;
+    symbol_registry.reserved_until = 0;
+    symbol_registry.bump = *ctx.bumps.get("symbol_registry").unwrap();
+
+    // Every launch consumes the launch pass bound to the creator's profile
+    let launch_pass = &mut ctx.accounts.launch_pass;
+    let pass_token_account = &ctx.accounts.launch_pass_token_account;
+
+    let bound_pass_mint = creator.launch_pass_mint.ok_or(CustomError::LaunchPassRequired)?;
+    require!(bound_pass_mint == launch_pass.mint, CustomError::InvalidLaunchPass);
+    require!(pass_token_account.mint == launch_pass.mint, CustomError::InvalidLaunchPass);
+    require!(
+        pass_token_account.owner == ctx.accounts.creator.key() && pass_token_account.amount == 1,
+        CustomError::LaunchPassNotOwned
+    );
+    require!(!launch_pass.is_used, CustomError::LaunchPassUsed);
+    if let Some(expires_at) = launch_pass.expires_at {
+        require!(clock.unix_timestamp < expires_at, CustomError::LaunchPassExpired);
     }
 
-    // Initialize the token mint
-    token::initialize_mint(
-        ctx.accounts.init_mint_ctx(),
-        decimals,
-        &ctx.accounts.creator.key(),
-        Some(&ctx.accounts.creator.key()),
-    )?;
+    launch_pass.owner = ctx.accounts.creator.key();
+    launch_pass.is_used = true;
+    launch_pass.used_for_token = Some(ctx.accounts.mint.key());
+    creator.launch_pass_mint = None;
+
+    // Create the Metaplex metadata account so wallets and explorers can display the token
+    CreateMetadataAccountV3CpiBuilder::new(&ctx.accounts.token_metadata_program.to_account_info())
+        .metadata(&ctx.accounts.metaplex_metadata.to_account_info())
+        .mint(&ctx.accounts.mint.to_account_info())
+        .mint_authority(&ctx.accounts.creator.to_account_info())
+        .payer(&ctx.accounts.creator.to_account_info())
+        .update_authority(&ctx.accounts.creator.to_account_info(), true)
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .rent(Some(&ctx.accounts.rent.to_account_info()))
+        .data(DataV2 {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(!immutable_metadata)
+        .invoke()?;
 
     // Mint total supply to creator's account
     token::mint_to(
@@ -139,28 +348,113 @@ pub fn handler(
         total_supply,
     )?;
 
+    // Revoke freeze authority so holders can never be frozen
+    token::set_authority(
+        ctx.accounts.set_authority_ctx(),
+        AuthorityType::FreezeAccount,
+        None,
+    )?;
+
+    // Revoke mint authority so the supply can never be inflated
+    if fixed_supply {
+        token::set_authority(
+            ctx.accounts.set_authority_ctx(),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+    } else {
+        // Hand minting to the program so new supply can only be issued via governance
+        let (mint_authority, _) = Pubkey::find_program_address(
+            &[b"mint_authority", ctx.accounts.mint.key().as_ref()],
+            ctx.program_id,
+        );
+        token::set_authority(
+            ctx.accounts.set_authority_ctx(),
+            AuthorityType::MintTokens,
+            Some(mint_authority),
+        )?;
+    }
+
     // Calculate creator allocation
     let creator_amount = (total_supply as u128 * creator_percent as u128 / 100) as u64;
     let public_amount = total_supply - creator_amount;
 
-    // Transfer creator's portion to vesting account
+    // Escrow the creator allocation until each beneficiary's vesting PDA is created
     token::transfer(
-        ctx.accounts.transfer_to_vesting_ctx(),
+        ctx.accounts.transfer_to_allocation_ctx(),
         creator_amount,
     )?;
 
-    // Initialize vesting schedule
-    let vesting = &mut ctx.accounts.vesting;
-    vesting.owner = ctx.accounts.creator.key();
-    vesting.mint = ctx.accounts.mint.key();
-    vesting.amount = creator_amount;
-    vesting.start_time = clock.unix_timestamp;
-    vesting.cliff_time = clock.unix_timestamp + vesting_seconds;
-    vesting.end_time = clock.unix_timestamp + vesting_seconds;
-    vesting.released = 0;
-    vesting.is_revocable = false;
-    vesting.revoked = false;
-    vesting.revoke_time = None;
+    // The whole public allocation goes on the curve, so the creator never holds it
+    require!(public_amount > 0, CustomError::InvalidBondingCurve);
+    require!(virtual_token_reserves > public_amount, CustomError::InvalidBondingCurve);
+    token::transfer(
+        ctx.accounts.transfer_to_curve_ctx(),
+        public_amount,
+    )?;
+
+    // Fund the SOL vault up to rent exemption so the first trade can never fail on rent
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.creator.to_account_info(),
+                to: ctx.accounts.curve_sol_vault.to_account_info(),
+            },
+        ),
+        ctx.accounts.rent.minimum_balance(0),
+    )?;
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.mint = ctx.accounts.mint.key();
+    bonding_curve.creator = ctx.accounts.creator.key();
+    bonding_curve.virtual_sol_reserves = virtual_sol_reserves;
+    bonding_curve.virtual_token_reserves = virtual_token_reserves;
+    bonding_curve.real_sol_reserves = 0;
+    bonding_curve.real_token_reserves = public_amount;
+    bonding_curve.initial_real_token_reserves = public_amount;
+    bonding_curve.total_volume = 0;
+    bonding_curve.total_fees = 0;
+    bonding_curve.complete = false;
+    bonding_curve.created_at = clock.unix_timestamp;
+    bonding_curve.last_trade_time = 0;
+    bonding_curve.bump = *ctx.bumps.get("bonding_curve").unwrap();
+    bonding_curve.sol_vault_bump = *ctx.bumps.get("curve_sol_vault").unwrap();
+    bonding_curve.graduation_market_cap = GRADUATION_MARKET_CAP;
+    bonding_curve.lp_lock_seconds = lp_lock_seconds;
+    bonding_curve.graduated = false;
+
+    // Split the allocation by share; the last beneficiary absorbs rounding dust
+    let total_bps = creator_percent as u128 * 100;
+    let mut allocated: u64 = 0;
+    let last_index = beneficiaries.len() - 1;
+    let creator_allocation = &mut ctx.accounts.creator_allocation;
+    creator_allocation.mint = ctx.accounts.mint.key();
+    creator_allocation.creator = ctx.accounts.creator.key();
+    creator_allocation.total_amount = creator_amount;
+    creator_allocation.created_at = clock.unix_timestamp;
+    creator_allocation.beneficiaries = beneficiaries
+        .iter()
+        .enumerate()
+        .map(|(i, beneficiary)| {
+            let amount = if i == last_index {
+                creator_amount - allocated
+            } else {
+                (creator_amount as u128 * beneficiary.share_bps as u128 / total_bps) as u64
+            };
+            allocated += amount;
+            BeneficiaryAllocation {
+                wallet: beneficiary.wallet,
+                share_bps: beneficiary.share_bps,
+                cliff_seconds: beneficiary.cliff_seconds,
+                vesting_seconds: beneficiary.vesting_seconds,
+                amount,
+                vesting_created: false,
+                schedule: beneficiary.schedule,
+            }
+        })
+        .collect();
+    creator_allocation.bump = *ctx.bumps.get("creator_allocation").unwrap();
 
     // Initialize token metadata
     let token_metadata = &mut ctx.accounts.token_metadata;
@@ -184,6 +478,10 @@ pub fn handler(
     token_metadata.anti_bot_window = ANTI_BOT_WINDOW;
     token_metadata.max_transaction_size = total_supply * MAX_TRANSACTION_SIZE_PERCENT as u64 / 100;
     token_metadata.min_transaction_size = MIN_TRANSACTION_SIZE;
+    token_metadata.metaplex_metadata = ctx.accounts.metaplex_metadata.key();
+    token_metadata.metadata_is_mutable = !immutable_metadata;
+    token_metadata.is_fixed_supply = fixed_supply;
+    token_metadata.bump = *ctx.bumps.get("token_metadata").unwrap();
 
     // Initialize staking pool
     let staking_pool = &mut ctx.accounts.staking_pool;
@@ -197,6 +495,16 @@ pub fn handler(
     staking_pool.lock_period = LIQUIDITY_LOCK_PERIOD;
     staking_pool.early_withdrawal_fee = EARLY_WITHDRAWAL_FEE;
     staking_pool.is_active = true;
+    staking_pool.bump = *ctx.bumps.get("staking_pool").unwrap();
+    staking_pool.reward_per_token_stored = 0;
+    staking_pool.undistributed_rewards = 0;
+    staking_pool.reward_vault_bump = 0; // Set by initialize_staking_vaults
+    staking_pool.stake_vault_bump = 0;
+    staking_pool.total_weighted_staked = 0;
+    staking_pool.fee_revenue_per_token_stored = 0;
+    staking_pool.fee_weighted_staked = 0;
+    staking_pool.fee_revenue_pending = 0;
+    staking_pool.fee_revenue_release_end = 0;
 
     // Initialize anti-bot configuration
     let anti_bot_config = &mut ctx.accounts.anti_bot_config;
@@ -209,6 +517,7 @@ pub fn handler(
     anti_bot_config.whitelisted_addresses = Vec::new();
     anti_bot_config.max_wallet_percentage = 5; // 5% max per wallet
     anti_bot_config.max_transaction_percentage = 2; // 2% max per transaction
+    anti_bot_config.bump = *ctx.bumps.get("anti_bot_config").unwrap();
 
     // Initialize liquidity pool
     let liquidity_pool = &mut ctx.accounts.liquidity_pool;
@@ -221,44 +530,59 @@ pub fn handler(
     liquidity_pool.created_at = clock.unix_timestamp;
     liquidity_pool.last_swap_time = 0;
     liquidity_pool.total_volume = 0;
+    liquidity_pool.bump = *ctx.bumps.get("liquidity_pool").unwrap();
+    liquidity_pool.lp_mint = Pubkey::default(); // Set at graduation
+    liquidity_pool.sol_vault_bump = 0;
 
     // Update creator profile
     creator.last_creation_ts = clock.unix_timestamp;
     creator.total_tokens_created += 1;
+    creator.weekly_creation_count += 1;
 
     msg!(
-        "Token '{}' created successfully! Supply: {}, Creator: {}% (vested), Public: {}",
+        "Token '{}' created successfully! Supply: {}, Creator: {}% (vested), Public (bonding curve): {}",
         name,
         total_supply,
         creator_percent,
         public_amount
     );
+    msg!("Fixed supply (no mint / no freeze): {}", fixed_supply);
+    msg!("Creator allocation split across {} beneficiaries", beneficiaries.len());
 
     Ok(())
 }
 
 impl<'info> CreateToken<'info> {
-    pub fn init_mint_ctx(&self) -> CpiContext<'_, '_, '_, 'info, InitializeMint<'info>> {
-        let cpi_accounts = InitializeMint {
+    pub fn mint_to_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
             mint: self.mint.to_account_info(),
-            rent: self.rent.to_account_info(),
+            to: self.creator_token_account.to_account_info(),
+            authority: self.creator.to_account_info(),
         };
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
     }
 
-    pub fn mint_to_ctx(&self) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
-        let cpi_accounts = MintTo {
-            mint: self.mint.to_account_info(),
-            to: self.creator_token_account.to_account_info(),
+    pub fn set_authority_ctx(&self) -> CpiContext<'_, '_, '_, 'info, SetAuthority<'info>> {
+        let cpi_accounts = SetAuthority {
+            current_authority: self.creator.to_account_info(),
+            account_or_mint: self.mint.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    pub fn transfer_to_allocation_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let cpi_accounts = token::Transfer {
+            from: self.creator_token_account.to_account_info(),
+            to: self.allocation_vault.to_account_info(),
             authority: self.creator.to_account_info(),
         };
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
     }
 
-    pub fn transfer_to_vesting_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+    pub fn transfer_to_curve_ctx(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
         let cpi_accounts = token::Transfer {
             from: self.creator_token_account.to_account_info(),
-            to: self.vesting_token_account.to_account_info(),
+            to: self.curve_token_vault.to_account_info(),
             authority: self.creator.to_account_info(),
         };
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
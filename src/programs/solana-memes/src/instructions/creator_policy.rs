@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeCreatorPolicy<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CreatorPolicy::LEN,
+        seeds = [b"creator_policy"],
+        bump
+    )]
+    pub creator_policy: Account<'info, CreatorPolicy>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCreatorPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [b"creator_policy"],
+        bump = creator_policy.bump
+    )]
+    pub creator_policy: Account<'info, CreatorPolicy>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct CreatorPolicyUpdated {
+    pub tiers: Vec<CreatorTier>,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+pub fn initialize_creator_policy(ctx: Context<InitializeCreatorPolicy>) -> Result<()> {
+    let creator_policy = &mut ctx.accounts.creator_policy;
+    creator_policy.tiers = CreatorPolicy::default_tiers();
+    creator_policy.updated_at = Clock::get()?.unix_timestamp;
+    creator_policy.bump = *ctx.bumps.get("creator_policy").unwrap();
+
+    msg!("Creator policy initialized with {} tiers", creator_policy.tiers.len());
+
+    Ok(())
+}
+
+pub fn update_creator_policy(ctx: Context<UpdateCreatorPolicy>) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Proposal must have passed and cleared the execution delay
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+    require!(!ctx.accounts.proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(ctx.accounts.proposal.has_passed(current_time), CustomError::ProposalNotPassed);
+    require!(
+        current_time >= ctx.accounts.proposal.end_time + governance_config.execution_delay,
+        CustomError::ProposalNotPassed
+    );
+
+    let tiers = match &ctx.accounts.proposal.proposal_type {
+        ProposalType::CreatorPolicyUpdate { tiers } => tiers.clone(),
+        _ => return err!(CustomError::ProposalMismatch),
+    };
+    require!(CreatorPolicy::validate_tiers(&tiers), CustomError::InvalidCreatorPolicy);
+
+    let creator_policy = &mut ctx.accounts.creator_policy;
+    creator_policy.tiers = tiers.clone();
+    creator_policy.updated_at = current_time;
+
+    // Mark proposal as executed
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed = true;
+    proposal.executed_at = Some(current_time);
+    proposal.executed_by = Some(ctx.accounts.authority.key());
+    proposal.updated_at = current_time;
+
+    emit!(CreatorPolicyUpdated {
+        tiers,
+        proposal_id: proposal.id,
+        timestamp: current_time,
+    });
+
+    msg!("Creator policy updated under proposal {}", proposal.id);
+
+    Ok(())
+}
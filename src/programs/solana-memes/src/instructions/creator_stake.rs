@@ -0,0 +1,738 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeSlashingConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SlashingConfig::LEN,
+        seeds = [b"slashing_config"],
+        bump
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSlashingConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"slashing_config"],
+        bump = slashing_config.bump
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SlashCreatorStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"creator_profile", creator_profile.owner.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_stake", creator_profile.owner.as_ref()],
+        bump
+    )]
+    pub stake_escrow: SystemAccount<'info>,
+
+    /// The rugged token whose holders are compensated
+    #[account(
+        seeds = [b"token_metadata", token_metadata.mint.as_ref()],
+        bump = token_metadata.bump,
+        constraint = token_metadata.creator == creator_profile.owner @ CustomError::SlashNotAuthorized
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CompensationPool::LEN,
+        seeds = [b"compensation_pool", token_metadata.mint.as_ref()],
+        bump
+    )]
+    pub compensation_pool: Account<'info, CompensationPool>,
+
+    #[account(
+        seeds = [b"slashing_config"],
+        bump = slashing_config.bump
+    )]
+    pub slashing_config: Account<'info, SlashingConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.authority == platform_config.authority @ CustomError::InvalidAccount
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Passed `CreatorSlash` proposal targeting this creator
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Option<Account<'info, GovernanceProposal>>,
+
+    /// Alert against the creator, resolved with a penalty or ban; each alert backs one slash
+    #[account(
+        mut,
+        seeds = [b"suspicious_alert", alert.alert_id.checked_sub(1).ok_or(CustomError::InvalidAccount)?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub alert: Option<Account<'info, SuspiciousActivityAlert>>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PostCompensationRoot<'info> {
+    #[account(
+        mut,
+        seeds = [b"compensation_pool", compensation_pool.mint.as_ref()],
+        bump = compensation_pool.bump
+    )]
+    pub compensation_pool: Account<'info, CompensationPool>,
+
+    /// Holder snapshots of the rugged token are produced off-chain by the platform
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimCompensation<'info> {
+    #[account(
+        mut,
+        seeds = [b"compensation_pool", compensation_pool.mint.as_ref()],
+        bump = compensation_pool.bump
+    )]
+    pub compensation_pool: Account<'info, CompensationPool>,
+
+    /// One receipt per holder prevents double claims
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + CompensationClaim::LEN,
+        seeds = [b"compensation_claim", compensation_pool.key().as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub compensation_claim: Account<'info, CompensationClaim>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepCompensation<'info> {
+    #[account(
+        mut,
+        seeds = [b"compensation_pool", compensation_pool.mint.as_ref()],
+        bump = compensation_pool.bump
+    )]
+    pub compensation_pool: Account<'info, CompensationPool>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        constraint = treasury.authority == platform_config.authority @ CustomError::InvalidAccount
+    )]
+    pub treasury: Account<'info, PlatformTreasury>,
+
+    /// Anyone can sweep once the claim period ends
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RequestDeregistration<'info> {
+    #[account(
+        mut,
+        seeds = [b"creator_profile", creator.key().as_ref()],
+        bump = creator_profile.bump,
+        constraint = creator_profile.owner == creator.key() @ CustomError::Unauthorized
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    pub creator: Signer<'info>,
+}
+
+/// Remaining accounts: for each token the creator launched, its `CreatorAllocation`
+/// followed by the vesting of every beneficiary, in allocation order
+#[derive(Accounts)]
+pub struct WithdrawCreatorStake<'info> {
+    /// Kept as a tombstone so reputation, tier and penalties survive re-registration
+    #[account(
+        mut,
+        seeds = [b"creator_profile", creator.key().as_ref()],
+        bump = creator_profile.bump,
+        constraint = creator_profile.owner == creator.key() @ CustomError::Unauthorized
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        seeds = [b"creator_stake", creator.key().as_ref()],
+        bump
+    )]
+    pub stake_escrow: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct CompensationRootPosted {
+    pub pool: Pubkey,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub snapshot_slot: u64,
+    pub claim_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CompensationClaimed {
+    pub pool: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnclaimedCompensationSwept {
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorDeregistrationRequested {
+    pub creator: Pubkey,
+    pub withdrawable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorStakeWithdrawn {
+    pub creator: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreatorStakeSlashed {
+    pub creator: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub compensation_amount: u64,
+    pub treasury_amount: u64,
+    pub proposal_id: Option<u64>,
+    pub alert_id: Option<u64>,
+    pub timestamp: i64,
+}
+
+pub fn initialize_slashing_config(
+    ctx: Context<InitializeSlashingConfig>,
+    compensation_share_bps: u16,
+) -> Result<()> {
+    require!(compensation_share_bps <= 10_000, CustomError::InvalidSlashingConfig);
+
+    let slashing_config = &mut ctx.accounts.slashing_config;
+    slashing_config.compensation_share_bps = compensation_share_bps;
+    slashing_config.updated_at = Clock::get()?.unix_timestamp;
+    slashing_config.bump = *ctx.bumps.get("slashing_config").unwrap();
+
+    msg!("Slashing config initialized: {} bps to compensation", compensation_share_bps);
+
+    Ok(())
+}
+
+pub fn update_slashing_config(
+    ctx: Context<UpdateSlashingConfig>,
+    compensation_share_bps: u16,
+) -> Result<()> {
+    require!(compensation_share_bps <= 10_000, CustomError::InvalidSlashingConfig);
+
+    let slashing_config = &mut ctx.accounts.slashing_config;
+    slashing_config.compensation_share_bps = compensation_share_bps;
+    slashing_config.updated_at = Clock::get()?.unix_timestamp;
+
+    msg!("Slashing config updated: {} bps to compensation", compensation_share_bps);
+
+    Ok(())
+}
+
+pub fn slash_creator_stake(
+    ctx: Context<SlashCreatorStake>,
+    amount: u64,
+    reason: String,
+) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let creator_profile = &ctx.accounts.creator_profile;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+    require!(reason.len() <= MAX_BAN_REASON_LENGTH, CustomError::BanReasonTooLong);
+
+    // Slashing needs either a passed proposal or a confirmed alert
+    let mut proposal_id = None;
+    let mut alert_id = None;
+    if let Some(proposal) = &ctx.accounts.proposal {
+        require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+        require!(proposal.has_passed(current_time), CustomError::ProposalNotPassed);
+        require!(
+            current_time >= proposal.end_time + governance_config.execution_delay,
+            CustomError::ProposalNotPassed
+        );
+        match &proposal.proposal_type {
+            ProposalType::CreatorSlash { creator, amount: proposed } => {
+                require!(
+                    *creator == creator_profile.owner && *proposed == amount,
+                    CustomError::ProposalMismatch
+                );
+            },
+            _ => return err!(CustomError::ProposalMismatch),
+        }
+        proposal_id = Some(proposal.id);
+    } else if let Some(alert) = &ctx.accounts.alert {
+        require!(
+            ctx.accounts.authority.key() == governance_config.authority,
+            CustomError::Unauthorized
+        );
+        require!(
+            alert.status == AlertStatus::Resolved
+                && matches!(
+                    alert.resolution,
+                    Some(AlertResolution::Penalty) | Some(AlertResolution::Ban)
+                ),
+            CustomError::SlashNotAuthorized
+        );
+        require!(alert.target_wallet == creator_profile.owner, CustomError::SlashNotAuthorized);
+        require!(!alert.slashed, CustomError::AlertAlreadySlashed);
+        alert_id = Some(alert.alert_id);
+    } else {
+        return err!(CustomError::SlashNotAuthorized);
+    }
+
+    // A partial slash must leave the escrow rent-exempt
+    let escrow_balance = ctx.accounts.stake_escrow.lamports();
+    require!(amount > 0 && amount <= escrow_balance, CustomError::InsufficientCreatorStake);
+    let remaining = escrow_balance - amount;
+    require!(
+        remaining == 0 || remaining >= Rent::get()?.minimum_balance(0),
+        CustomError::InsufficientCreatorStake
+    );
+
+    // Once holders are snapshotted, later slashes go entirely to the treasury
+    let compensation_amount = if ctx.accounts.compensation_pool.root_posted() {
+        0
+    } else {
+        (amount as u128 * ctx.accounts.slashing_config.compensation_share_bps as u128 / 10_000) as u64
+    };
+    let treasury_amount = amount - compensation_amount;
+
+    let creator_key = creator_profile.owner;
+    let seeds = &[
+        b"creator_stake".as_ref(),
+        creator_key.as_ref(),
+        &[*ctx.bumps.get("stake_escrow").unwrap()],
+    ];
+    let signer = &[&seeds[..]];
+
+    if compensation_amount > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.compensation_pool.to_account_info(),
+                },
+                signer,
+            ),
+            compensation_amount,
+        )?;
+    }
+    if treasury_amount > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+                signer,
+            ),
+            treasury_amount,
+        )?;
+    }
+
+    let compensation_pool = &mut ctx.accounts.compensation_pool;
+    if compensation_pool.mint == Pubkey::default() {
+        compensation_pool.mint = ctx.accounts.token_metadata.mint;
+        compensation_pool.creator = creator_key;
+        compensation_pool.total_received = 0;
+        compensation_pool.created_at = current_time;
+        compensation_pool.bump = *ctx.bumps.get("compensation_pool").unwrap();
+    }
+    compensation_pool.total_received = compensation_pool.total_received
+        .checked_add(compensation_amount)
+        .ok_or(CustomError::MathOverflow)?;
+    compensation_pool.updated_at = current_time;
+
+    ctx.accounts.treasury.last_updated = current_time;
+
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    creator_profile.stake_amount = creator_profile.stake_amount.saturating_sub(amount);
+    creator_profile.is_banned = true;
+    creator_profile.ban_reason = reason;
+
+    if let Some(proposal) = &mut ctx.accounts.proposal {
+        proposal.executed = true;
+        proposal.executed_at = Some(current_time);
+        proposal.executed_by = Some(ctx.accounts.authority.key());
+        proposal.updated_at = current_time;
+    }
+    if let Some(alert) = &mut ctx.accounts.alert {
+        alert.slashed = true;
+        alert.updated_at = current_time;
+    }
+
+    emit!(CreatorStakeSlashed {
+        creator: creator_key,
+        mint: ctx.accounts.token_metadata.mint,
+        amount,
+        compensation_amount,
+        treasury_amount,
+        proposal_id,
+        alert_id,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Creator {} slashed {} lamports ({} to compensation, {} to treasury) and banned",
+        creator_key,
+        amount,
+        compensation_amount,
+        treasury_amount
+    );
+
+    Ok(())
+}
+
+pub fn post_compensation_root(
+    ctx: Context<PostCompensationRoot>,
+    merkle_root: [u8; 32],
+    snapshot_slot: u64,
+) -> Result<()> {
+    let compensation_pool = &mut ctx.accounts.compensation_pool;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!compensation_pool.root_posted(), CustomError::CompensationRootPosted);
+    require!(merkle_root != [0u8; 32], CustomError::InvalidMerkleProof);
+    require!(compensation_pool.total_received > 0, CustomError::NothingToClaim);
+
+    compensation_pool.merkle_root = merkle_root;
+    compensation_pool.snapshot_slot = snapshot_slot;
+    compensation_pool.total_claimed = 0;
+    compensation_pool.claim_count = 0;
+    compensation_pool.claim_deadline = current_time + DISTRIBUTION_CLAIM_PERIOD;
+    compensation_pool.swept = false;
+    compensation_pool.updated_at = current_time;
+
+    emit!(CompensationRootPosted {
+        pool: compensation_pool.key(),
+        mint: compensation_pool.mint,
+        merkle_root,
+        total_amount: compensation_pool.total_received,
+        snapshot_slot,
+        claim_deadline: compensation_pool.claim_deadline,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Compensation of {} lamports posted for snapshot slot {}, claimable until {}",
+        compensation_pool.total_received,
+        snapshot_slot,
+        compensation_pool.claim_deadline
+    );
+
+    Ok(())
+}
+
+pub fn claim_compensation(
+    ctx: Context<ClaimCompensation>,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let compensation_pool = &ctx.accounts.compensation_pool;
+    let holder = ctx.accounts.holder.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(compensation_pool.root_posted(), CustomError::CompensationRootNotPosted);
+    require!(!compensation_pool.swept, CustomError::DistributionSwept);
+    require!(
+        current_time <= compensation_pool.claim_deadline,
+        CustomError::DistributionClaimPeriodEnded
+    );
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(
+        verify_merkle_claim(&compensation_pool.merkle_root, &holder, amount, &proof),
+        CustomError::InvalidMerkleProof
+    );
+
+    let total_claimed = compensation_pool.total_claimed
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(
+        total_claimed <= compensation_pool.total_received,
+        CustomError::InsufficientFunds
+    );
+
+    // The pool holds the lamports itself, so pay out directly above its rent reserve
+    **ctx.accounts.compensation_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.holder.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    let compensation_claim = &mut ctx.accounts.compensation_claim;
+    compensation_claim.pool = ctx.accounts.compensation_pool.key();
+    compensation_claim.holder = holder;
+    compensation_claim.amount = amount;
+    compensation_claim.claimed_at = current_time;
+
+    let compensation_pool = &mut ctx.accounts.compensation_pool;
+    compensation_pool.total_claimed = total_claimed;
+    compensation_pool.claim_count = compensation_pool.claim_count.saturating_add(1);
+    compensation_pool.updated_at = current_time;
+
+    emit!(CompensationClaimed {
+        pool: compensation_pool.key(),
+        holder,
+        amount,
+        timestamp: current_time,
+    });
+
+    msg!("Holder {} claimed {} lamports of compensation", holder, amount);
+
+    Ok(())
+}
+
+pub fn sweep_compensation(ctx: Context<SweepCompensation>) -> Result<()> {
+    let compensation_pool = &ctx.accounts.compensation_pool;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(compensation_pool.root_posted(), CustomError::CompensationRootNotPosted);
+    require!(!compensation_pool.swept, CustomError::DistributionSwept);
+    require!(
+        current_time > compensation_pool.claim_deadline,
+        CustomError::DistributionClaimPeriodOpen
+    );
+
+    let amount = compensation_pool.total_received
+        .checked_sub(compensation_pool.total_claimed)
+        .ok_or(CustomError::MathOverflow)?;
+    if amount > 0 {
+        **ctx.accounts.compensation_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.treasury.to_account_info().try_borrow_mut_lamports()? += amount;
+    }
+
+    ctx.accounts.treasury.last_updated = current_time;
+
+    let compensation_pool = &mut ctx.accounts.compensation_pool;
+    compensation_pool.swept = true;
+    compensation_pool.updated_at = current_time;
+
+    emit!(UnclaimedCompensationSwept {
+        pool: compensation_pool.key(),
+        amount,
+        timestamp: current_time,
+    });
+
+    msg!("Swept {} unclaimed compensation lamports to the treasury", amount);
+
+    Ok(())
+}
+
+pub fn request_deregistration(ctx: Context<RequestDeregistration>) -> Result<()> {
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(creator_profile.is_registered, CustomError::NotRegistered);
+    require!(!creator_profile.is_banned, CustomError::CreatorBanned);
+    require!(
+        creator_profile.deregistration_requested_at.is_none(),
+        CustomError::CreatorDeregistering
+    );
+
+    // No new launches from here on; the stake stays slashable until the cooldown ends
+    creator_profile.deregistration_requested_at = Some(current_time);
+
+    emit!(CreatorDeregistrationRequested {
+        creator: creator_profile.owner,
+        withdrawable_at: current_time + CREATOR_DEREGISTRATION_COOLDOWN,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Creator {} requested deregistration, stake withdrawable at {}",
+        creator_profile.owner,
+        current_time + CREATOR_DEREGISTRATION_COOLDOWN
+    );
+
+    Ok(())
+}
+
+pub fn withdraw_creator_stake(ctx: Context<WithdrawCreatorStake>) -> Result<()> {
+    let creator_profile = &ctx.accounts.creator_profile;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Slashed creators forfeit whatever is left
+    require!(creator_profile.is_registered, CustomError::NotRegistered);
+    require!(!creator_profile.is_banned, CustomError::CreatorBanned);
+    let requested_at = creator_profile.deregistration_requested_at
+        .ok_or(CustomError::DeregistrationNotRequested)?;
+    require!(
+        current_time >= requested_at + CREATOR_DEREGISTRATION_COOLDOWN,
+        CustomError::DeregistrationCooldownActive
+    );
+
+    verify_vestings_settled(
+        ctx.program_id,
+        &creator_profile.owner,
+        creator_profile.total_tokens_created,
+        ctx.remaining_accounts,
+    )?;
+
+    let amount = ctx.accounts.stake_escrow.lamports();
+    if amount > 0 {
+        let creator_key = ctx.accounts.creator.key();
+        let seeds = &[
+            b"creator_stake".as_ref(),
+            creator_key.as_ref(),
+            &[*ctx.bumps.get("stake_escrow").unwrap()],
+        ];
+        let signer = &[&seeds[..]];
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.stake_escrow.to_account_info(),
+                    to: ctx.accounts.creator.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+    }
+
+    let creator_profile = &mut ctx.accounts.creator_profile;
+    creator_profile.is_registered = false;
+    creator_profile.stake_amount = 0;
+    creator_profile.deregistration_requested_at = None;
+
+    emit!(CreatorStakeWithdrawn {
+        creator: ctx.accounts.creator.key(),
+        amount,
+        timestamp: current_time,
+    });
+
+    msg!("Creator {} withdrew {} lamports of stake and deregistered", ctx.accounts.creator.key(), amount);
+
+    Ok(())
+}
+
+/// Every vesting of every token the creator launched must be fully released or revoked
+fn verify_vestings_settled(
+    program_id: &Pubkey,
+    creator: &Pubkey,
+    tokens_created: u32,
+    accounts: &[AccountInfo],
+) -> Result<()> {
+    let mut accounts = accounts.iter();
+    let mut seen_mints: Vec<Pubkey> = Vec::new();
+
+    for _ in 0..tokens_created {
+        let allocation_info = accounts.next().ok_or(CustomError::VestingsNotSettled)?;
+        let allocation = Account::<CreatorAllocation>::try_from(allocation_info)?;
+        let (expected_allocation, _) = Pubkey::find_program_address(
+            &[b"creator_allocation", allocation.mint.as_ref()],
+            program_id,
+        );
+        require!(
+            allocation_info.key() == expected_allocation && allocation.creator == *creator,
+            CustomError::InvalidAccount
+        );
+        require!(!seen_mints.contains(&allocation.mint), CustomError::InvalidAccount);
+        seen_mints.push(allocation.mint);
+
+        for beneficiary in allocation.beneficiaries.iter() {
+            require!(beneficiary.vesting_created, CustomError::VestingsNotSettled);
+            let vesting_info = accounts.next().ok_or(CustomError::VestingsNotSettled)?;
+            let vesting = Account::<Vesting>::try_from(vesting_info)?;
+            let (expected_vesting, _) = Pubkey::find_program_address(
+                &[b"vesting", allocation.mint.as_ref(), beneficiary.wallet.as_ref()],
+                program_id,
+            );
+            require!(vesting_info.key() == expected_vesting, CustomError::InvalidAccount);
+            require!(
+                vesting.revoked || vesting.released >= vesting.amount,
+                CustomError::VestingsNotSettled
+            );
+        }
+    }
+
+    Ok(())
+}
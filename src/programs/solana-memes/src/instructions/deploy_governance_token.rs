@@ -6,10 +6,20 @@ use crate::security_utils::SecurityUtils;
 
 #[derive(Accounts)]
 pub struct DeployGovernanceToken<'info> {
-    #[account(mut)]
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GovernanceToken::LEN
+    )]
     pub governance_token: Account<'info, GovernanceToken>,
     
-    #[account(mut)]
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GovernanceConfig::LEN,
+        seeds = [b"governance_config"],
+        bump
+    )]
     pub governance_config: Account<'info, GovernanceConfig>,
     
     #[account(mut)]
@@ -68,6 +78,7 @@ pub fn handler(ctx: Context<DeployGovernanceToken>) -> Result<()> {
     governance_config.is_active = false; // Will be activated after token distribution
     governance_config.created_at = current_time;
     governance_config.updated_at = current_time;
+    governance_config.proposal_count = 0;
     
     msg!("Governance token deployed successfully!");
     msg!("Token Mint: {}", token_mint.key());
@@ -82,7 +93,11 @@ pub struct ActivateGovernance<'info> {
     #[account(mut)]
     pub governance_token: Account<'info, GovernanceToken>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump
+    )]
     pub governance_config: Account<'info, GovernanceConfig>,
     
     pub authority: Signer<'info>,
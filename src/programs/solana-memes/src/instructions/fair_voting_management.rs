@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use crate::state::*;
 use crate::errors::CustomError;
 use crate::fair_voting_utils::FairVotingUtils;
@@ -98,45 +98,37 @@ pub struct InitializeEnhancedTokenHolder<'info> {
     )]
     pub enhanced_token_holder: Account<'info, EnhancedTokenHolder>,
     
+    #[account(mut)]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
-pub fn initialize_enhanced_token_holder(
-    ctx: Context<InitializeEnhancedTokenHolder>,
-    staked_amount: u64,
-    staking_duration: i64,
-    community_contribution: u64,
-    token_holding: u64,
-    consistency_score: u64,
-    reputation_score: i32,
-    participation_history: u64,
-    contribution_quality: u64,
-) -> Result<()> {
+pub fn initialize_enhanced_token_holder(ctx: Context<InitializeEnhancedTokenHolder>) -> Result<()> {
     let enhanced_token_holder = &mut ctx.accounts.enhanced_token_holder;
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Initialize enhanced token holder
+    // Initialize enhanced token holder; every voting component is derived
+    // from on-chain state by `refresh_voting_power`
     enhanced_token_holder.holder = ctx.accounts.authority.key();
     enhanced_token_holder.balance = 0;
     enhanced_token_holder.voting_power = 0;
     
     // Multi-factor voting components
-    enhanced_token_holder.staked_amount = staked_amount;
-    enhanced_token_holder.staking_duration = staking_duration;
-    enhanced_token_holder.community_contribution_score = community_contribution;
-    enhanced_token_holder.token_holding_amount = token_holding;
+    enhanced_token_holder.staked_amount = 0;
+    enhanced_token_holder.staking_duration = 0;
+    enhanced_token_holder.community_contribution_score = 0;
+    enhanced_token_holder.token_holding_amount = 0;
     
     // Anti-manipulation factors
-    enhanced_token_holder.consistency_score = consistency_score;
-    enhanced_token_holder.reputation_score = reputation_score;
-    enhanced_token_holder.participation_history = participation_history;
-    enhanced_token_holder.contribution_quality = contribution_quality;
+    enhanced_token_holder.consistency_score = 0;
+    enhanced_token_holder.reputation_score = 0;
+    enhanced_token_holder.participation_history = 0;
+    enhanced_token_holder.contribution_quality = 0;
     
     // Time-based factors
-    enhanced_token_holder.first_stake_time = current_time;
-    enhanced_token_holder.last_stake_time = current_time;
+    enhanced_token_holder.first_stake_time = 0;
+    enhanced_token_holder.last_stake_time = 0;
     enhanced_token_holder.total_staking_time = 0;
     
     // Whale detection
@@ -157,80 +149,247 @@ pub fn initialize_enhanced_token_holder(
     
     msg!("Enhanced token holder initialized successfully!");
     msg!("Holder: {}", ctx.accounts.authority.key());
-    msg!("Staked amount: {}", staked_amount);
-    msg!("Reputation score: {}", reputation_score);
     
     Ok(())
 }
 
 #[derive(Accounts)]
-pub struct UpdateVotingPower<'info> {
-    #[account(mut)]
+pub struct RefreshVotingPower<'info> {
+    #[account(
+        mut,
+        seeds = [b"enhanced_token_holder", enhanced_token_holder.holder.as_ref()],
+        bump
+    )]
     pub enhanced_token_holder: Account<'info, EnhancedTokenHolder>,
     
-    #[account(mut)]
+    #[account(
+        seeds = [b"fair_voting_safeguards"],
+        bump
+    )]
     pub fair_voting_safeguards: Account<'info, FairVotingSafeguards>,
     
-    pub authority: Signer<'info>,
+    /// Designates the platform staking pool voting power is measured against
+    #[account(
+        seeds = [b"fee_revenue_pool"],
+        bump = fee_revenue_pool.bump,
+        has_one = staking_pool @ CustomError::InvalidAccount
+    )]
+    pub fee_revenue_pool: Account<'info, FeeRevenuePool>,
     
-    pub system_program: Program<'info, System>,
+    #[account(
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+    
+    #[account(address = staking_pool.mint @ CustomError::InvalidAccount)]
+    pub token_mint: Account<'info, Mint>,
+    
+    /// Liquid balance, used only for whale detection; it carries no voting power
+    #[account(
+        token::mint = staking_pool.mint,
+        token::authority = enhanced_token_holder.holder
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: The holder's creator profile PDA, always passed and read whenever it exists,
+    /// so a creator cannot leave out their track record
+    #[account(
+        seeds = [b"creator_profile", enhanced_token_holder.holder.as_ref()],
+        bump
+    )]
+    pub creator_profile: UncheckedAccount<'info>,
+    
+    /// Only the holder refreshes, so no one can zero their power just before they vote
+    #[account(address = enhanced_token_holder.holder @ CustomError::Unauthorized)]
+    pub holder: Signer<'info>,
+    
+    // remaining_accounts: the holder's StakingPosition PDA for every tier, in tier order
 }
 
-pub fn update_voting_power(
-    ctx: Context<UpdateVotingPower>,
-    new_staked_amount: u64,
-    new_staking_duration: i64,
-    new_community_contribution: u64,
-    new_token_holding: u64,
-    new_consistency_score: u64,
-    new_reputation_score: i32,
-    new_participation_history: u64,
-    new_contribution_quality: u64,
-) -> Result<()> {
-    let enhanced_token_holder = &mut ctx.accounts.enhanced_token_holder;
+/// Load the holder's positions in `staking_pool`. `accounts` must hold the StakingPosition PDA of
+/// every tier in tier order, so no position can be left out; tiers never staked are skipped.
+pub fn load_tier_positions<'info>(
+    accounts: &[AccountInfo<'info>],
+    staking_pool: &Pubkey,
+    holder: &Pubkey,
+    program_id: &Pubkey,
+) -> Result<Vec<Account<'info, StakingPosition>>> {
+    require!(accounts.len() == StakeTier::COUNT, CustomError::InvalidAccount);
+    
+    let mut positions = Vec::with_capacity(StakeTier::COUNT);
+    for (tier, account_info) in accounts.iter().enumerate() {
+        let (address, _) = Pubkey::find_program_address(
+            &[b"staking_position", staking_pool.as_ref(), holder.as_ref(), &[tier as u8]],
+            program_id,
+        );
+        require!(account_info.key() == address, CustomError::InvalidAccount);
+        if account_info.owner != program_id || account_info.data_is_empty() {
+            continue;
+        }
+        positions.push(Account::<StakingPosition>::try_from(account_info)?);
+    }
+    Ok(positions)
+}
+
+pub fn refresh_voting_power(ctx: Context<RefreshVotingPower>) -> Result<()> {
     let fair_voting_safeguards = &ctx.accounts.fair_voting_safeguards;
+    let staking_pool_key = ctx.accounts.staking_pool.key();
+    let holder = ctx.accounts.enhanced_token_holder.holder;
     let current_time = Clock::get()?.unix_timestamp;
     
-    // Validate authority
-    require!(
-        enhanced_token_holder.holder == ctx.accounts.authority.key(),
-        CustomError::UnauthorizedFeeChange
-    );
+    // Sum the holder's stake across all of their positions in the platform pool
+    let positions = load_tier_positions(ctx.remaining_accounts, &staking_pool_key, &holder, ctx.program_id)?;
+    let mut staked_amount: u64 = 0;
+    let mut locked_amount: u64 = 0;
+    let mut first_stake_time: Option<i64> = None;
+    let mut last_stake_time: Option<i64> = None;
+    let mut weighted_duration: u128 = 0;
+    for position in positions.iter() {
+        if position.amount == 0 {
+            continue;
+        }
+        
+        staked_amount = staked_amount
+            .checked_add(position.amount)
+            .ok_or(CustomError::MathOverflow)?;
+        // Expired locks keep `is_locked` until unstake, so they no longer count as locked
+        if position.is_locked && position.end_time > current_time {
+            locked_amount = locked_amount
+                .checked_add(position.amount)
+                .ok_or(CustomError::MathOverflow)?;
+        }
+        // A top-up restarts its position's clock, so stake moved in from another wallet
+        // earns no duration credit
+        let held_for = current_time.saturating_sub(position.last_deposit_time).max(0) as u128;
+        weighted_duration = weighted_duration
+            .checked_add(held_for * position.amount as u128)
+            .ok_or(CustomError::MathOverflow)?;
+        first_stake_time = Some(first_stake_time.map_or(position.start_time, |t| t.min(position.start_time)));
+        last_stake_time = Some(last_stake_time.map_or(position.start_time, |t| t.max(position.start_time)));
+    }
+    
+    // Stake-weighted time since each position's last deposit
+    let staking_duration = if staked_amount > 0 {
+        (weighted_duration / staked_amount as u128) as i64
+    } else {
+        0
+    };
+    // Liquid balances can be moved and refreshed again from another wallet, so the
+    // holding factor counts only stake locked in a tier
+    let token_holding = locked_amount;
+    let liquid_balance = ctx.accounts.holder_token_account.amount;
+    
+    // Consistency: share of the stake committed to locked tiers
+    let consistency_score = if staked_amount > 0 {
+        (locked_amount as u128 * 100 / staked_amount as u128) as u64
+    } else {
+        0
+    };
     
-    // Update token holder data
-    enhanced_token_holder.staked_amount = new_staked_amount;
-    enhanced_token_holder.staking_duration = new_staking_duration;
-    enhanced_token_holder.community_contribution_score = new_community_contribution;
-    enhanced_token_holder.token_holding_amount = new_token_holding;
-    enhanced_token_holder.consistency_score = new_consistency_score;
-    enhanced_token_holder.reputation_score = new_reputation_score;
-    enhanced_token_holder.participation_history = new_participation_history;
-    enhanced_token_holder.contribution_quality = new_contribution_quality;
-    
-    // Calculate new voting power
-    let new_voting_power = FairVotingUtils::calculate_fair_voting_power(
-        new_staked_amount,
-        new_staking_duration,
-        new_community_contribution,
-        new_token_holding,
-        new_consistency_score,
-        new_reputation_score,
-        new_participation_history,
-        new_contribution_quality,
-        fair_voting_safeguards,
-    )?;
+    // Creator track record, if the holder has a creator profile
+    let profile_info = ctx.accounts.creator_profile.to_account_info();
+    let creator_profile = if profile_info.owner == ctx.program_id && !profile_info.data_is_empty() {
+        Some(Account::<CreatorProfile>::try_from(&profile_info)?)
+    } else {
+        None
+    };
+    let creator_banned = creator_profile.as_ref().map_or(false, |profile| profile.is_banned);
+    let (reputation_score, community_contribution, contribution_quality) =
+        match &creator_profile {
+            Some(profile) => {
+                let quality = if profile.total_tokens_created > 0 {
+                    profile.successful_tokens as u64 * 100 / profile.total_tokens_created as u64
+                } else {
+                    0
+                };
+                (
+                    profile.reputation_score.clamp(-100, 1000),
+                    profile.total_profit_shared,
+                    quality,
+                )
+            }
+            None => (0, 0, 0),
+        };
     
-    enhanced_token_holder.voting_power = new_voting_power;
+    let enhanced_token_holder = &mut ctx.accounts.enhanced_token_holder;
+    let participation_history = enhanced_token_holder.participation_history;
+    
+    // Banned creators and holders below the stake or duration minimums carry no voting power
+    let voting_power = if creator_banned
+        || staked_amount == 0
+        || staked_amount < fair_voting_safeguards.minimum_staked_amount
+        || staking_duration < fair_voting_safeguards.minimum_staking_duration
+    {
+        0
+    } else {
+        FairVotingUtils::calculate_fair_voting_power(
+            staked_amount,
+            staking_duration,
+            community_contribution,
+            token_holding,
+            consistency_score,
+            reputation_score,
+            participation_history,
+            contribution_quality,
+            fair_voting_safeguards,
+        )?
+    };
+    
+    // Whale detection against the staked token's supply
+    let supply = ctx.accounts.token_mint.supply;
+    let holdings = staked_amount
+        .checked_add(liquid_balance)
+        .ok_or(CustomError::MathOverflow)?;
+    let concentration = if supply > 0 {
+        (holdings as u128 * 100 / supply as u128).min(100) as u8
+    } else {
+        0
+    };
+    
+    enhanced_token_holder.balance = liquid_balance;
+    enhanced_token_holder.voting_power = voting_power;
+    enhanced_token_holder.staked_amount = staked_amount;
+    enhanced_token_holder.staking_duration = staking_duration;
+    enhanced_token_holder.community_contribution_score = community_contribution;
+    enhanced_token_holder.token_holding_amount = token_holding;
+    enhanced_token_holder.consistency_score = consistency_score;
+    enhanced_token_holder.reputation_score = reputation_score;
+    enhanced_token_holder.contribution_quality = contribution_quality;
+    enhanced_token_holder.first_stake_time = first_stake_time.unwrap_or(0);
+    enhanced_token_holder.last_stake_time = last_stake_time.unwrap_or(0);
+    enhanced_token_holder.total_staking_time = staking_duration;
+    enhanced_token_holder.concentration_percentage = concentration;
+    enhanced_token_holder.is_whale = concentration > fair_voting_safeguards.max_concentration_percent;
     enhanced_token_holder.updated_at = current_time;
     
-    msg!("Voting power updated successfully!");
-    msg!("New voting power: {}", new_voting_power);
-    msg!("Staked amount: {}", new_staked_amount);
-    msg!("Reputation score: {}", new_reputation_score);
+    emit!(VotingPowerRefreshed {
+        holder,
+        voting_power,
+        staked_amount,
+        token_holding,
+        participation_history,
+        timestamp: current_time,
+    });
+    
+    msg!("Voting power refreshed successfully!");
+    msg!("Holder: {}", holder);
+    msg!("New voting power: {}", voting_power);
+    msg!("Staked amount: {}", staked_amount);
     
     Ok(())
 }
 
+#[event]
+pub struct VotingPowerRefreshed {
+    pub holder: Pubkey,
+    pub voting_power: u64,
+    pub staked_amount: u64,
+    pub token_holding: u64,
+    pub participation_history: u64,
+    pub timestamp: i64,
+}
+
 #[derive(Accounts)]
 pub struct InitializeCreatorPerformance<'info> {
     #[account(
@@ -248,6 +407,15 @@ pub struct InitializeCreatorPerformance<'info> {
     /// CHECK: Token mint account
     pub token_mint: AccountInfo<'info>,
     
+    /// Performance scores gate vesting releases, so only the governance authority may write them
+    #[account(
+        seeds = [b"governance_config"],
+        bump,
+        constraint = governance_config.authority == authority.key() @ CustomError::Unauthorized
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    
+    #[account(mut)]
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
@@ -317,9 +485,24 @@ pub fn initialize_creator_performance(
 
 #[derive(Accounts)]
 pub struct UpdateCreatorPerformance<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            b"creator_performance",
+            creator_performance.creator.as_ref(),
+            creator_performance.token_mint.as_ref()
+        ],
+        bump
+    )]
     pub creator_performance: Account<'info, CreatorPerformance>,
     
+    #[account(
+        seeds = [b"governance_config"],
+        bump,
+        constraint = governance_config.authority == authority.key() @ CustomError::Unauthorized
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    
     pub authority: Signer<'info>,
     
     pub system_program: Program<'info, System>,
@@ -353,7 +536,8 @@ pub fn update_creator_performance(
     let new_performance_score = FairVotingUtils::calculate_performance_score(creator_performance)?;
     
     // Update performance history
-    creator_performance.performance_history.push(creator_performance.performance_score);
+    let previous_score = creator_performance.performance_score;
+    creator_performance.performance_history.push(previous_score);
     creator_performance.performance_score = new_performance_score;
     creator_performance.last_assessment_time = current_time;
     creator_performance.updated_at = current_time;
@@ -587,7 +771,7 @@ pub fn create_suspicious_activity_alert(
     // Validate alert data
     require!(suspicious_activity.len() <= 500, CustomError::InvalidFeeProposal);
     require!(evidence.len() <= 1000, CustomError::InvalidFeeProposal);
-    require!(evidence_strength <= 100, CustomError::RISK_SCORE_INVALID);
+    require!(evidence_strength <= 100, CustomError::RiskScoreInvalid);
     
     // Initialize alert
     alert.alert_id = detection_system.total_alerts + 1;
@@ -615,6 +799,7 @@ pub fn create_suspicious_activity_alert(
     alert.investigator = None;
     alert.investigation_notes = "".to_string();
     alert.action_taken = "".to_string();
+    alert.slashed = false;
     
     alert.updated_at = current_time;
     
@@ -631,6 +816,67 @@ pub fn create_suspicious_activity_alert(
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct ResolveSuspiciousActivityAlert<'info> {
+    #[account(
+        mut,
+        seeds = [b"suspicious_alert", alert.alert_id.checked_sub(1).ok_or(CustomError::InvalidAccount)?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub alert: Account<'info, SuspiciousActivityAlert>,
+    
+    #[account(
+        mut,
+        seeds = [b"detection_system"],
+        bump
+    )]
+    pub detection_system: Account<'info, DetectionSystem>,
+    
+    /// Resolutions can revoke vesting and slash stakes, so only the governance authority may resolve
+    #[account(
+        seeds = [b"governance_config"],
+        bump,
+        constraint = governance_config.authority == authority.key() @ CustomError::UnauthorizedResolution
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn resolve_suspicious_activity_alert(
+    ctx: Context<ResolveSuspiciousActivityAlert>,
+    resolution: AlertResolution,
+) -> Result<()> {
+    let alert = &mut ctx.accounts.alert;
+    let detection_system = &mut ctx.accounts.detection_system;
+    let current_time = Clock::get()?.unix_timestamp;
+    
+    require!(
+        alert.status == AlertStatus::Open || alert.status == AlertStatus::UnderInvestigation,
+        CustomError::ActivityAlreadyResolved
+    );
+    
+    // No action means the alert did not hold up
+    if resolution == AlertResolution::NoAction {
+        alert.status = AlertStatus::FalsePositive;
+        detection_system.false_positives += 1;
+    } else {
+        alert.status = AlertStatus::Resolved;
+        detection_system.confirmed_manipulations += 1;
+    }
+    alert.resolution = Some(resolution);
+    alert.reviewed_at = Some(alert.reviewed_at.unwrap_or(current_time));
+    alert.resolved_at = Some(current_time);
+    alert.investigator = Some(ctx.accounts.authority.key());
+    alert.updated_at = current_time;
+    detection_system.updated_at = current_time;
+    
+    msg!("Suspicious activity alert {} resolved", alert.alert_id);
+    msg!("Target wallet: {}", alert.target_wallet);
+    
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct InitializePenaltySystem<'info> {
     #[account(
@@ -717,7 +963,7 @@ pub fn issue_penalty(
     // Validate penalty data
     require!(reason.len() <= 500, CustomError::InvalidFeeProposal);
     require!(evidence.len() <= 1000, CustomError::InvalidFeeProposal);
-    require!(risk_score <= 100, CustomError::RISK_SCORE_INVALID);
+    require!(risk_score <= 100, CustomError::RiskScoreInvalid);
     
     // Determine penalty amount based on type
     let penalty_amount = match penalty_type {
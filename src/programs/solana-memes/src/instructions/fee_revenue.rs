@@ -0,0 +1,282 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeFeeRevenuePool<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeRevenuePool::LEN,
+        seeds = [b"fee_revenue_pool"],
+        bump
+    )]
+    pub fee_revenue_pool: Account<'info, FeeRevenuePool>,
+
+    /// Stakers in this pool earn the platform's fee revenue
+    #[account(
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// SOL vault holding the stakers' share of trading fees
+    #[account(
+        mut,
+        seeds = [b"fee_revenue_vault"],
+        bump
+    )]
+    pub fee_revenue_vault: SystemAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimFeeRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_revenue_pool"],
+        bump = fee_revenue_pool.bump,
+        has_one = staking_pool @ CustomError::InvalidAccount
+    )]
+    pub fee_revenue_pool: Account<'info, FeeRevenuePool>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            owner.key().as_ref(),
+            &[staking_position.tier as u8]
+        ],
+        bump = staking_position.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub staking_position: Account<'info, StakingPosition>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_revenue_vault"],
+        bump = fee_revenue_pool.vault_bump
+    )]
+    pub fee_revenue_vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseFeeEpoch<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_revenue_pool"],
+        bump = fee_revenue_pool.bump,
+        has_one = staking_pool @ CustomError::InvalidAccount
+    )]
+    pub fee_revenue_pool: Account<'info, FeeRevenuePool>,
+
+    #[account(
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeRevenueEpoch::LEN,
+        seeds = [b"fee_revenue_epoch", fee_revenue_pool.current_epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub fee_revenue_epoch: Account<'info, FeeRevenueEpoch>,
+
+    /// Anyone can close an epoch once it has run its course
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct FeeRevenueClaimed {
+    pub owner: Pubkey,
+    pub position: Pubkey,
+    pub amount: u64,
+    pub epoch: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeeEpochClosed {
+    pub epoch: u64,
+    pub revenue: u64,
+    pub claimed: u64,
+    pub total_weighted_staked: u64,
+    pub revenue_per_token: u128,
+    pub timestamp: i64,
+}
+
+pub fn initialize_fee_revenue_pool(
+    ctx: Context<InitializeFeeRevenuePool>,
+    epoch_duration: i64,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(epoch_duration > 0, CustomError::InvalidFeeEpochDuration);
+
+    // Keep the vault rent-exempt so small claims can never drain it below the minimum
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let shortfall = rent_exempt_minimum.saturating_sub(ctx.accounts.fee_revenue_vault.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.fee_revenue_vault.to_account_info(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    let fee_revenue_pool = &mut ctx.accounts.fee_revenue_pool;
+    fee_revenue_pool.staking_pool = ctx.accounts.staking_pool.key();
+    fee_revenue_pool.total_deposited = 0;
+    fee_revenue_pool.total_claimed = 0;
+    fee_revenue_pool.current_epoch = 0;
+    fee_revenue_pool.epoch_start = current_time;
+    fee_revenue_pool.epoch_duration = epoch_duration;
+    fee_revenue_pool.epoch_revenue = 0;
+    fee_revenue_pool.epoch_claimed = 0;
+    fee_revenue_pool.vault_bump = *ctx.bumps.get("fee_revenue_vault").unwrap();
+    fee_revenue_pool.bump = *ctx.bumps.get("fee_revenue_pool").unwrap();
+
+    msg!(
+        "Fee revenue pool initialized for staking pool {} with {}s epochs",
+        fee_revenue_pool.staking_pool,
+        epoch_duration
+    );
+
+    Ok(())
+}
+
+pub fn claim_fee_revenue(ctx: Context<ClaimFeeRevenue>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let staking_position = &mut ctx.accounts.staking_position;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    staking_pool.update_rewards(current_time).ok_or(CustomError::MathOverflow)?;
+    staking_position.settle_rewards(staking_pool).ok_or(CustomError::MathOverflow)?;
+
+    let amount = staking_position.pending_fee_revenue;
+    require!(amount > 0, CustomError::NoFeeRevenueToClaim);
+    staking_position.pending_fee_revenue = 0;
+
+    let seeds = &[b"fee_revenue_vault".as_ref(), &[ctx.accounts.fee_revenue_pool.vault_bump]];
+    let signer = &[&seeds[..]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.fee_revenue_vault.to_account_info(),
+                to: ctx.accounts.owner.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    let fee_revenue_pool = &mut ctx.accounts.fee_revenue_pool;
+    fee_revenue_pool.total_claimed = fee_revenue_pool.total_claimed
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    fee_revenue_pool.epoch_claimed = fee_revenue_pool.epoch_claimed
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(FeeRevenueClaimed {
+        owner: ctx.accounts.owner.key(),
+        position: ctx.accounts.staking_position.key(),
+        amount,
+        epoch: fee_revenue_pool.current_epoch,
+        timestamp: current_time,
+    });
+
+    msg!("Claimed {} lamports of fee revenue", amount);
+
+    Ok(())
+}
+
+pub fn close_fee_epoch(ctx: Context<CloseFeeEpoch>) -> Result<()> {
+    let fee_revenue_pool = &mut ctx.accounts.fee_revenue_pool;
+    let staking_pool = &ctx.accounts.staking_pool;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        current_time >= fee_revenue_pool.epoch_start + fee_revenue_pool.epoch_duration,
+        CustomError::FeeEpochNotEnded
+    );
+
+    let revenue_per_token = if staking_pool.fee_weighted_staked == 0 {
+        0
+    } else {
+        (fee_revenue_pool.epoch_revenue as u128)
+            .checked_mul(REWARD_PER_TOKEN_PRECISION)
+            .ok_or(CustomError::MathOverflow)?
+            / staking_pool.fee_weighted_staked as u128
+    };
+
+    let fee_revenue_epoch = &mut ctx.accounts.fee_revenue_epoch;
+    fee_revenue_epoch.epoch = fee_revenue_pool.current_epoch;
+    fee_revenue_epoch.start_time = fee_revenue_pool.epoch_start;
+    fee_revenue_epoch.end_time = current_time;
+    fee_revenue_epoch.revenue = fee_revenue_pool.epoch_revenue;
+    fee_revenue_epoch.claimed = fee_revenue_pool.epoch_claimed;
+    fee_revenue_epoch.total_staked = staking_pool.total_staked;
+    fee_revenue_epoch.total_weighted_staked = staking_pool.fee_weighted_staked;
+    fee_revenue_epoch.revenue_per_token = revenue_per_token;
+    fee_revenue_epoch.bump = *ctx.bumps.get("fee_revenue_epoch").unwrap();
+
+    fee_revenue_pool.current_epoch += 1;
+    fee_revenue_pool.epoch_start = current_time;
+    fee_revenue_pool.epoch_revenue = 0;
+    fee_revenue_pool.epoch_claimed = 0;
+
+    emit!(FeeEpochClosed {
+        epoch: fee_revenue_epoch.epoch,
+        revenue: fee_revenue_epoch.revenue,
+        claimed: fee_revenue_epoch.claimed,
+        total_weighted_staked: fee_revenue_epoch.total_weighted_staked,
+        revenue_per_token,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Fee epoch {} closed: {} lamports earned, {} claimed",
+        fee_revenue_epoch.epoch,
+        fee_revenue_epoch.revenue,
+        fee_revenue_epoch.claimed
+    );
+
+    Ok(())
+}
@@ -2,13 +2,24 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 use crate::state::*;
 use crate::errors::CustomError;
+use crate::instructions::fair_voting_management::load_tier_positions;
 
 #[derive(Accounts)]
 pub struct CreateGovernanceProposal<'info> {
-    #[account(mut)]
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GovernanceProposal::LEN,
+        seeds = [b"governance_proposal", governance_config.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
     pub proposal: Account<'info, GovernanceProposal>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump
+    )]
     pub governance_config: Account<'info, GovernanceConfig>,
     
     #[account(mut)]
@@ -17,6 +28,7 @@ pub struct CreateGovernanceProposal<'info> {
     #[account(mut)]
     pub creator_token_account: Account<'info, TokenAccount>,
     
+    #[account(mut)]
     pub authority: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
@@ -30,7 +42,7 @@ pub fn create_proposal(
     proposal_type: ProposalType,
 ) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
-    let governance_config = &ctx.accounts.governance_config;
+    let governance_config = &mut ctx.accounts.governance_config;
     let creator = &ctx.accounts.creator;
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -44,11 +56,13 @@ pub fn create_proposal(
     );
     
     // Validate proposal data
-    require!(title.len() <= 200, CustomError::InvalidFeeProposal);
-    require!(description.len() <= 2000, CustomError::InvalidFeeProposal);
+    require!(title.len() <= GovernanceProposal::MAX_TITLE_LEN, CustomError::InvalidFeeProposal);
+    require!(description.len() <= GovernanceProposal::MAX_DESCRIPTION_LEN, CustomError::InvalidFeeProposal);
+    require!(proposal_type.is_within_bounds(), CustomError::InvalidFeeProposal);
     
-    // Initialize proposal
-    proposal.id = proposal.id + 1; // Increment proposal ID
+    // Initialize proposal at the next PDA index
+    proposal.id = governance_config.proposal_count;
+    governance_config.proposal_count += 1;
     proposal.creator = ctx.accounts.authority.key();
     proposal.title = title;
     proposal.description = description;
@@ -76,25 +90,61 @@ pub fn create_proposal(
 
 #[derive(Accounts)]
 pub struct VoteOnProposal<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
     pub proposal: Account<'info, GovernanceProposal>,
     
-    #[account(mut)]
+    /// One vote per (proposal, voter); a second vote fails to re-initialize it
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Vote::LEN,
+        seeds = [b"vote", proposal.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
     pub vote: Account<'info, Vote>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = voter.holder == authority.key() @ CustomError::Unauthorized
+    )]
     pub voter: Account<'info, TokenHolder>,
     
     #[account(mut)]
     pub voter_token_account: Account<'info, TokenAccount>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump
+    )]
     pub governance_config: Account<'info, GovernanceConfig>,
     
+    /// Supplies the voter's refreshed voting power and records their participation
+    #[account(
+        mut,
+        seeds = [b"enhanced_token_holder", authority.key().as_ref()],
+        bump
+    )]
+    pub enhanced_token_holder: Account<'info, EnhancedTokenHolder>,
+    
+    /// Designates the platform staking pool whose positions back the vote
+    #[account(
+        seeds = [b"fee_revenue_pool"],
+        bump = fee_revenue_pool.bump
+    )]
+    pub fee_revenue_pool: Account<'info, FeeRevenuePool>,
+    
+    #[account(mut)]
     pub authority: Signer<'info>,
     
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+    
+    // remaining_accounts: the voter's StakingPosition PDA for every tier, in tier order (writable)
 }
 
 pub fn vote(
@@ -103,7 +153,7 @@ pub fn vote(
 ) -> Result<()> {
     let proposal = &mut ctx.accounts.proposal;
     let vote = &mut ctx.accounts.vote;
-    let voter = &ctx.accounts.voter;
+    let enhanced_token_holder = &mut ctx.accounts.enhanced_token_holder;
     let governance_config = &ctx.accounts.governance_config;
     let current_time = Clock::get()?.unix_timestamp;
     
@@ -116,36 +166,68 @@ pub fn vote(
         CustomError::VotingPeriodEnded
     );
     
+    // Voting power must be refreshed after the proposal opened, so stake moved in
+    // or out during the vote is reflected
+    require!(
+        enhanced_token_holder.updated_at >= proposal.start_time,
+        CustomError::VotingPowerNotRefreshed
+    );
+    let voting_power = enhanced_token_holder.voting_power;
+    
+    // Lock the counted stake until voting closes, so it cannot be unstaked, moved to
+    // another wallet and counted again
+    let mut positions = load_tier_positions(
+        ctx.remaining_accounts,
+        &ctx.accounts.fee_revenue_pool.staking_pool,
+        &ctx.accounts.authority.key(),
+        ctx.program_id,
+    )?;
+    let mut staked_amount: u64 = 0;
+    for position in positions.iter_mut() {
+        staked_amount = staked_amount
+            .checked_add(position.amount)
+            .ok_or(CustomError::MathOverflow)?;
+        position.vote_locked_until = position.vote_locked_until.max(proposal.end_time);
+        position.exit(ctx.program_id)?;
+    }
+    require!(
+        staked_amount >= enhanced_token_holder.staked_amount,
+        CustomError::VotingPowerNotRefreshed
+    );
+    
     // Check if voter has minimum voting power
     require!(
-        voter.voting_power >= governance_config.min_voting_power,
+        voting_power >= governance_config.min_voting_power,
         CustomError::InsufficientVotingPower
     );
     
-    // Check if voter hasn't already voted
-    require!(vote.voter == Pubkey::default(), CustomError::AlreadyVoted);
-    
     // Record the vote
     vote.proposal_id = proposal.id;
     vote.voter = ctx.accounts.authority.key();
     vote.vote_type = vote_type;
-    vote.voting_power = voter.voting_power;
+    vote.voting_power = voting_power;
     vote.voted_at = current_time;
     
     // Update proposal vote counts
     match vote_type {
         VoteType::Yes => {
-            proposal.yes_votes += voter.voting_power;
+            proposal.yes_votes = proposal.yes_votes
+                .checked_add(voting_power)
+                .ok_or(CustomError::MathOverflow)?;
         },
         VoteType::No => {
-            proposal.no_votes += voter.voting_power;
+            proposal.no_votes = proposal.no_votes
+                .checked_add(voting_power)
+                .ok_or(CustomError::MathOverflow)?;
         },
         VoteType::Abstain => {
             // Abstain votes don't count towards quorum but are recorded
         },
     }
     
-    proposal.total_votes += voter.voting_power;
+    proposal.total_votes = proposal.total_votes
+        .checked_add(voting_power)
+        .ok_or(CustomError::MathOverflow)?;
     proposal.updated_at = current_time;
     
     // Check if quorum is met
@@ -153,12 +235,17 @@ pub fn vote(
         proposal.quorum_met = true;
     }
     
-    // Update voter's last vote time
-    // Note: In a real implementation, you'd update the voter's last_vote field
+    // Record participation; it feeds the holder's next voting power refresh.
+    // `updated_at` is left alone so it keeps marking the last refresh.
+    enhanced_token_holder.participation_history = enhanced_token_holder
+        .participation_history
+        .checked_add(1)
+        .ok_or(CustomError::MathOverflow)?;
+    enhanced_token_holder.last_vote = current_time;
     
     msg!("Vote recorded successfully!");
     msg!("Vote Type: {:?}", vote_type);
-    msg!("Voting Power: {}", voter.voting_power);
+    msg!("Voting Power: {}", voting_power);
     msg!("Total Votes: {}", proposal.total_votes);
     msg!("Quorum Met: {}", proposal.quorum_met);
     
@@ -167,10 +254,18 @@ pub fn vote(
 
 #[derive(Accounts)]
 pub struct ExecuteProposal<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
     pub proposal: Account<'info, GovernanceProposal>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"governance_config"],
+        bump
+    )]
     pub governance_config: Account<'info, GovernanceConfig>,
     
     #[account(mut)]
@@ -234,6 +329,15 @@ pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
         ProposalType::EmergencyAction => {
             msg!("Executing emergency action proposal");
         },
+        ProposalType::SymbolRelease { .. }
+        | ProposalType::CreatorPolicyUpdate { .. }
+        | ProposalType::VestingRevocation { .. }
+        | ProposalType::VestingTransferPolicyUpdate { .. }
+        | ProposalType::StakingTierUpdate { .. }
+        | ProposalType::CreatorSlash { .. } => {
+            // Executed by the dedicated instruction that performs the action
+            return err!(CustomError::ProposalMismatch);
+        },
     }
     
     // Mark proposal as executed
@@ -0,0 +1,375 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::CustomError;
+use crate::fair_voting_utils::FairVotingUtils;
+
+#[derive(Accounts)]
+pub struct Graduate<'info> {
+    #[account(
+        mut,
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = mint
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = bonding_curve
+    )]
+    pub curve_token_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"bonding_curve_sol_vault", mint.key().as_ref()],
+        bump = bonding_curve.sol_vault_bump
+    )]
+    pub curve_sol_vault: SystemAccount<'info>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"liquidity_pool", mint.key().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"lp_mint", mint.key().as_ref()],
+        bump,
+        mint::decimals = 9,
+        mint::authority = liquidity_pool
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    /// Deterministic ATA that anyone could create ahead of graduation, so it is reused if it exists
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = liquidity_pool
+    )]
+    pub pool_token_vault: Account<'info, TokenAccount>,
+
+    /// SOL vault holding pool reserves
+    #[account(
+        mut,
+        seeds = [b"liquidity_pool_sol_vault", mint.key().as_ref()],
+        bump
+    )]
+    pub pool_sol_vault: SystemAccount<'info>,
+
+    /// Holds LP tokens awaiting a delayed burn; they never leave the vault
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = lp_mint,
+        associated_token::authority = liquidity_pool
+    )]
+    pub lp_vault: Account<'info, TokenAccount>,
+
+    /// Anyone can crank graduation once the curve is complete
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[event]
+pub struct TokenGraduated {
+    pub mint: Pubkey,
+    pub sol_amount: u64,
+    pub token_amount: u64,
+    pub tokens_burned: u64,
+    pub lp_amount: u64,
+    pub lp_burned: bool,
+    pub liquidity_lock_end: i64,            // When the LP tokens can be burned; i64::MAX once burned
+    pub timestamp: i64,
+}
+
+pub fn handler(ctx: Context<Graduate>) -> Result<()> {
+    let clock = Clock::get()?;
+    let bonding_curve = &ctx.accounts.bonding_curve;
+
+    require!(bonding_curve.complete, CustomError::BondingCurveNotComplete);
+    require!(!bonding_curve.graduated, CustomError::AlreadyGraduated);
+
+    let sol_amount = bonding_curve.real_sol_reserves;
+    require!(sol_amount > 0, CustomError::InsufficientCurveLiquidity);
+
+    // Seed the pool at the curve's closing price; unsold tokens beyond that are burned
+    let tokens_at_price = (sol_amount as u128)
+        .checked_mul(bonding_curve.virtual_token_reserves as u128)
+        .ok_or(CustomError::MathOverflow)?
+        / bonding_curve.virtual_sol_reserves as u128;
+    let token_amount = std::cmp::min(bonding_curve.real_token_reserves as u128, tokens_at_price) as u64;
+    let tokens_burned = bonding_curve.real_token_reserves
+        .checked_sub(token_amount)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(token_amount > 0, CustomError::InsufficientCurveLiquidity);
+
+    let lp_lock_seconds = bonding_curve.lp_lock_seconds;
+    let mint_key = ctx.accounts.mint.key();
+    let curve_seeds = &[b"bonding_curve".as_ref(), mint_key.as_ref(), &[bonding_curve.bump]];
+    let curve_signer = &[&curve_seeds[..]];
+    let curve_vault_seeds = &[
+        b"bonding_curve_sol_vault".as_ref(),
+        mint_key.as_ref(),
+        &[bonding_curve.sol_vault_bump],
+    ];
+    let curve_vault_signer = &[&curve_vault_seeds[..]];
+    let pool_seeds = &[b"liquidity_pool".as_ref(), mint_key.as_ref(), &[ctx.accounts.liquidity_pool.bump]];
+    let pool_signer = &[&pool_seeds[..]];
+
+    // Move remaining curve tokens into the pool
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.curve_token_vault.to_account_info(),
+                to: ctx.accounts.pool_token_vault.to_account_info(),
+                authority: ctx.accounts.bonding_curve.to_account_info(),
+            },
+            curve_signer,
+        ),
+        token_amount,
+    )?;
+    if tokens_burned > 0 {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.curve_token_vault.to_account_info(),
+                    authority: ctx.accounts.bonding_curve.to_account_info(),
+                },
+                curve_signer,
+            ),
+            tokens_burned,
+        )?;
+    }
+
+    // Make the pool SOL vault rent exempt, then move the curve SOL into it
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.pool_sol_vault.to_account_info(),
+            },
+        ),
+        ctx.accounts.rent.minimum_balance(0),
+    )?;
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.curve_sol_vault.to_account_info(),
+                to: ctx.accounts.pool_sol_vault.to_account_info(),
+            },
+            curve_vault_signer,
+        ),
+        sol_amount,
+    )?;
+
+    // Mint initial LP tokens as the geometric mean of the reserves
+    let lp_amount = FairVotingUtils::integer_sqrt(
+        (sol_amount as u128)
+            .checked_mul(token_amount as u128)
+            .ok_or(CustomError::MathOverflow)?,
+    );
+    require!(lp_amount > 0, CustomError::InvalidLiquidityAmount);
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                to: ctx.accounts.lp_vault.to_account_info(),
+                authority: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+            pool_signer,
+        ),
+        lp_amount,
+    )?;
+
+    // Burn the LP tokens now, or hold them in the pool-owned vault for a delayed burn.
+    // Either way the liquidity can never be withdrawn.
+    let lp_burned = lp_lock_seconds == 0;
+    let liquidity_lock_end = if lp_burned {
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    from: ctx.accounts.lp_vault.to_account_info(),
+                    authority: ctx.accounts.liquidity_pool.to_account_info(),
+                },
+                pool_signer,
+            ),
+            lp_amount,
+        )?;
+        i64::MAX
+    } else {
+        clock.unix_timestamp
+            .checked_add(lp_lock_seconds)
+            .ok_or(CustomError::MathOverflow)?
+    };
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.token_reserve = token_amount;
+    liquidity_pool.sol_reserve = sol_amount;
+    liquidity_pool.lp_supply = if lp_burned { 0 } else { lp_amount };
+    liquidity_pool.is_active = true;
+    liquidity_pool.lp_mint = ctx.accounts.lp_mint.key();
+    liquidity_pool.sol_vault_bump = *ctx.bumps.get("pool_sol_vault").unwrap();
+
+    let token_metadata = &mut ctx.accounts.token_metadata;
+    token_metadata.total_supply = token_metadata.total_supply
+        .checked_sub(tokens_burned)
+        .ok_or(CustomError::MathOverflow)?;
+    token_metadata.circulating_supply = token_metadata.circulating_supply
+        .checked_sub(tokens_burned)
+        .ok_or(CustomError::MathOverflow)?;
+    token_metadata.liquidity_locked = true;
+    token_metadata.liquidity_lock_amount = lp_amount;
+    token_metadata.liquidity_lock_end = liquidity_lock_end;
+
+    let bonding_curve = &mut ctx.accounts.bonding_curve;
+    bonding_curve.real_sol_reserves = 0;
+    bonding_curve.real_token_reserves = 0;
+    bonding_curve.graduated = true;
+
+    emit!(TokenGraduated {
+        mint: mint_key,
+        sol_amount,
+        token_amount,
+        tokens_burned,
+        lp_amount,
+        lp_burned,
+        liquidity_lock_end,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Token {} graduated to the liquidity pool", mint_key);
+    msg!("Pool reserves: {} lamports, {} tokens", sol_amount, token_amount);
+    msg!("LP tokens: {} ({})", lp_amount, if lp_burned { "burned" } else { "burn delayed" });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BurnLockedLiquidity<'info> {
+    #[account(
+        seeds = [b"bonding_curve", mint.key().as_ref()],
+        bump = bonding_curve.bump,
+        has_one = mint
+    )]
+    pub bonding_curve: Account<'info, BondingCurve>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"liquidity_pool", mint.key().as_ref()],
+        bump = liquidity_pool.bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        mut,
+        seeds = [b"lp_mint", mint.key().as_ref()],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_mint,
+        associated_token::authority = liquidity_pool
+    )]
+    pub lp_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct LockedLiquidityBurned {
+    pub mint: Pubkey,
+    pub lp_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Delayed burn of the LP tokens held since graduation: `lp_lock_seconds` only postpones the
+/// burn, it is not a lock that ends in a release. Permissionless, since the LP never leaves the
+/// pool-owned vault and no one can withdraw the curve's SOL.
+pub fn burn_locked_liquidity(ctx: Context<BurnLockedLiquidity>) -> Result<()> {
+    let clock = Clock::get()?;
+    let token_metadata = &ctx.accounts.token_metadata;
+
+    require!(ctx.accounts.bonding_curve.graduated, CustomError::BondingCurveNotComplete);
+    require!(
+        clock.unix_timestamp >= token_metadata.liquidity_lock_end,
+        CustomError::LiquidityLockPeriodNotMet
+    );
+
+    let lp_amount = ctx.accounts.lp_vault.amount;
+    require!(lp_amount > 0, CustomError::InvalidLiquidityAmount);
+
+    let mint_key = ctx.accounts.mint.key();
+    let pool_seeds = &[b"liquidity_pool".as_ref(), mint_key.as_ref(), &[ctx.accounts.liquidity_pool.bump]];
+    let pool_signer = &[&pool_seeds[..]];
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.lp_mint.to_account_info(),
+                from: ctx.accounts.lp_vault.to_account_info(),
+                authority: ctx.accounts.liquidity_pool.to_account_info(),
+            },
+            pool_signer,
+        ),
+        lp_amount,
+    )?;
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.lp_supply = liquidity_pool.lp_supply.saturating_sub(lp_amount);
+
+    // Burned liquidity stays locked forever, as when graduation burns the LP directly
+    let token_metadata = &mut ctx.accounts.token_metadata;
+    token_metadata.liquidity_lock_end = i64::MAX;
+
+    emit!(LockedLiquidityBurned {
+        mint: mint_key,
+        lp_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Burned {} time-locked LP tokens for {}", lp_amount, mint_key);
+
+    Ok(())
+}
@@ -0,0 +1,347 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct PostDistributionRoot<'info> {
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting.mint.as_ref(), vesting.beneficiary.as_ref()],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vesting
+    )]
+    pub vesting_token_account: Account<'info, TokenAccount>,
+
+    #[account(address = vesting.mint @ CustomError::InvalidAccount)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HolderDistribution::LEN,
+        seeds = [b"holder_distribution", vesting.key().as_ref()],
+        bump
+    )]
+    pub holder_distribution: Account<'info, HolderDistribution>,
+
+    /// Deterministic ATA that anyone could create first, so it is reused if it exists
+    #[account(
+        init_if_needed,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = holder_distribution
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// Snapshots are produced off-chain by the platform and posted as a merkle root
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimDistribution<'info> {
+    #[account(
+        mut,
+        seeds = [b"holder_distribution", holder_distribution.vesting.as_ref()],
+        bump = holder_distribution.bump
+    )]
+    pub holder_distribution: Account<'info, HolderDistribution>,
+
+    #[account(
+        mut,
+        associated_token::mint = holder_distribution.mint,
+        associated_token::authority = holder_distribution
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    /// One receipt per holder prevents double claims
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + DistributionClaim::LEN,
+        seeds = [b"distribution_claim", holder_distribution.key().as_ref(), holder.key().as_ref()],
+        bump
+    )]
+    pub distribution_claim: Account<'info, DistributionClaim>,
+
+    #[account(
+        mut,
+        token::mint = holder_distribution.mint,
+        token::authority = holder
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepDistribution<'info> {
+    #[account(
+        mut,
+        seeds = [b"holder_distribution", holder_distribution.vesting.as_ref()],
+        bump = holder_distribution.bump
+    )]
+    pub holder_distribution: Account<'info, HolderDistribution>,
+
+    #[account(
+        mut,
+        associated_token::mint = holder_distribution.mint,
+        associated_token::authority = holder_distribution
+    )]
+    pub distribution_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(
+        mut,
+        token::mint = holder_distribution.mint,
+        constraint = treasury_token_account.owner == platform_config.authority @ CustomError::InvalidAccount
+    )]
+    pub treasury_token_account: Account<'info, TokenAccount>,
+
+    /// Anyone can sweep once the claim period ends
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct HolderDistributionCreated {
+    pub distribution: Pubkey,
+    pub vesting: Pubkey,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],
+    pub total_amount: u64,
+    pub snapshot_slot: u64,
+    pub claim_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DistributionClaimed {
+    pub distribution: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnclaimedDistributionSwept {
+    pub distribution: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+pub fn post_distribution_root(
+    ctx: Context<PostDistributionRoot>,
+    merkle_root: [u8; 32],
+    snapshot_slot: u64,
+) -> Result<()> {
+    let vesting = &ctx.accounts.vesting;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        vesting.choice_made && vesting.distribution_choice == Some(VestingOption::Distribute),
+        CustomError::InvalidVestingOption
+    );
+    require!(!vesting.revoked, CustomError::VestingRevoked);
+
+    // The holders' half was left in the vesting vault by `choose_vesting_option`
+    let total_amount = vesting.amount
+        .checked_sub(vesting.released)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(total_amount > 0, CustomError::NothingToClaim);
+
+    let mint_key = vesting.mint;
+    let beneficiary_key = vesting.beneficiary;
+    let seeds = &[b"vesting".as_ref(), mint_key.as_ref(), beneficiary_key.as_ref(), &[vesting.bump]];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vesting_token_account.to_account_info(),
+                to: ctx.accounts.distribution_vault.to_account_info(),
+                authority: ctx.accounts.vesting.to_account_info(),
+            },
+            signer,
+        ),
+        total_amount,
+    )?;
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.released = vesting.amount;
+
+    let holder_distribution = &mut ctx.accounts.holder_distribution;
+    holder_distribution.vesting = vesting.key();
+    holder_distribution.mint = mint_key;
+    holder_distribution.merkle_root = merkle_root;
+    holder_distribution.total_amount = total_amount;
+    holder_distribution.claimed_amount = 0;
+    holder_distribution.claim_count = 0;
+    holder_distribution.snapshot_slot = snapshot_slot;
+    holder_distribution.created_at = current_time;
+    holder_distribution.claim_deadline = current_time + DISTRIBUTION_CLAIM_PERIOD;
+    holder_distribution.swept = false;
+    holder_distribution.bump = *ctx.bumps.get("holder_distribution").unwrap();
+
+    emit!(HolderDistributionCreated {
+        distribution: holder_distribution.key(),
+        vesting: holder_distribution.vesting,
+        mint: mint_key,
+        merkle_root,
+        total_amount,
+        snapshot_slot,
+        claim_deadline: holder_distribution.claim_deadline,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Holder distribution of {} tokens posted for snapshot slot {}, claimable until {}",
+        total_amount,
+        snapshot_slot,
+        holder_distribution.claim_deadline
+    );
+
+    Ok(())
+}
+
+pub fn claim_distribution(
+    ctx: Context<ClaimDistribution>,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let holder_distribution = &ctx.accounts.holder_distribution;
+    let holder = ctx.accounts.holder.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!holder_distribution.swept, CustomError::DistributionSwept);
+    require!(
+        current_time <= holder_distribution.claim_deadline,
+        CustomError::DistributionClaimPeriodEnded
+    );
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(
+        holder_distribution.verify_claim(&holder, amount, &proof),
+        CustomError::InvalidMerkleProof
+    );
+
+    let claimed_amount = holder_distribution.claimed_amount
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(
+        claimed_amount <= holder_distribution.total_amount,
+        CustomError::InsufficientFunds
+    );
+
+    let vesting_key = holder_distribution.vesting;
+    let seeds = &[b"holder_distribution".as_ref(), vesting_key.as_ref(), &[holder_distribution.bump]];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.distribution_vault.to_account_info(),
+                to: ctx.accounts.holder_token_account.to_account_info(),
+                authority: ctx.accounts.holder_distribution.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    let distribution_claim = &mut ctx.accounts.distribution_claim;
+    distribution_claim.distribution = ctx.accounts.holder_distribution.key();
+    distribution_claim.holder = holder;
+    distribution_claim.amount = amount;
+    distribution_claim.claimed_at = current_time;
+
+    let holder_distribution = &mut ctx.accounts.holder_distribution;
+    holder_distribution.claimed_amount = claimed_amount;
+    holder_distribution.claim_count = holder_distribution.claim_count.saturating_add(1);
+
+    emit!(DistributionClaimed {
+        distribution: holder_distribution.key(),
+        holder,
+        amount,
+        timestamp: current_time,
+    });
+
+    msg!("Holder {} claimed {} tokens from distribution", holder, amount);
+
+    Ok(())
+}
+
+pub fn sweep_distribution(ctx: Context<SweepDistribution>) -> Result<()> {
+    let holder_distribution = &ctx.accounts.holder_distribution;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(!holder_distribution.swept, CustomError::DistributionSwept);
+    require!(
+        current_time > holder_distribution.claim_deadline,
+        CustomError::DistributionClaimPeriodOpen
+    );
+
+    let amount = ctx.accounts.distribution_vault.amount;
+    if amount > 0 {
+        let vesting_key = holder_distribution.vesting;
+        let seeds = &[b"holder_distribution".as_ref(), vesting_key.as_ref(), &[holder_distribution.bump]];
+        let signer = &[&seeds[..]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.distribution_vault.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.holder_distribution.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+    }
+
+    let holder_distribution = &mut ctx.accounts.holder_distribution;
+    holder_distribution.swept = true;
+
+    emit!(UnclaimedDistributionSwept {
+        distribution: holder_distribution.key(),
+        amount,
+        timestamp: current_time,
+    });
+
+    msg!("Swept {} unclaimed distribution tokens to the treasury", amount);
+
+    Ok(())
+}
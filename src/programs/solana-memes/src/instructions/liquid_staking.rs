@@ -0,0 +1,291 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount, Transfer};
+use mpl_token_metadata::instructions::CreateMetadataAccountV3CpiBuilder;
+use mpl_token_metadata::types::DataV2;
+use crate::state::*;
+use crate::errors::CustomError;
+use super::compounding_vault::compound_position;
+
+#[derive(Accounts)]
+pub struct InitializeReceiptMint<'info> {
+    #[account(
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"compounding_vault", staking_pool.key().as_ref()],
+        bump = compounding_vault.bump
+    )]
+    pub compounding_vault: Account<'info, CompoundingVault>,
+
+    #[account(address = staking_pool.mint @ CustomError::InvalidAccount)]
+    pub pool_mint: Account<'info, Mint>,
+
+    /// Receipt tokens are vault shares, so they carry the pool token's decimals
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"receipt_mint", compounding_vault.key().as_ref()],
+        bump,
+        mint::decimals = pool_mint.decimals,
+        mint::authority = compounding_vault
+    )]
+    pub receipt_mint: Account<'info, Mint>,
+
+    /// CHECK: Metaplex metadata PDA, initialized by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), receipt_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metaplex_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID @ CustomError::InvalidMetadataProgram)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct StakeLiquid<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"compounding_vault", staking_pool.key().as_ref()],
+        bump = compounding_vault.bump
+    )]
+    pub compounding_vault: Account<'info, CompoundingVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            compounding_vault.key().as_ref(),
+            &[StakeTier::Flexible as u8]
+        ],
+        bump = vault_position.bump
+    )]
+    pub vault_position: Account<'info, StakingPosition>,
+
+    #[account(
+        mut,
+        constraint = compounding_vault.receipt_mint == Some(receipt_mint.key()) @ CustomError::LiquidStakingNotEnabled
+    )]
+    pub receipt_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = receipt_mint
+    )]
+    pub receipt_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"staking_tiers"],
+        bump = staking_tier_config.bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.stake_vault_bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = user
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct LiquidStakingEnabled {
+    pub pool: Pubkey,
+    pub vault: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReceiptTokensMinted {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub receipt_amount: u64,
+    pub timestamp: i64,
+}
+
+pub fn initialize_receipt_mint(
+    ctx: Context<InitializeReceiptMint>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(
+        ctx.accounts.compounding_vault.receipt_mint.is_none(),
+        CustomError::LiquidStakingAlreadyEnabled
+    );
+    require!(name.len() <= mpl_token_metadata::MAX_NAME_LENGTH, CustomError::InvalidMetadataName);
+    require!(symbol.len() <= mpl_token_metadata::MAX_SYMBOL_LENGTH, CustomError::InvalidMetadataSymbol);
+    require!(uri.len() <= mpl_token_metadata::MAX_URI_LENGTH, CustomError::InvalidMetadataUri);
+
+    let pool_key = ctx.accounts.staking_pool.key();
+    let seeds = &[b"compounding_vault".as_ref(), pool_key.as_ref(), &[ctx.accounts.compounding_vault.bump]];
+    let signer = &[&seeds[..]];
+
+    // Name the receipt (e.g. stMEME) so wallets and DEXes can list it
+    CreateMetadataAccountV3CpiBuilder::new(&ctx.accounts.token_metadata_program.to_account_info())
+        .metadata(&ctx.accounts.metaplex_metadata.to_account_info())
+        .mint(&ctx.accounts.receipt_mint.to_account_info())
+        .mint_authority(&ctx.accounts.compounding_vault.to_account_info())
+        .payer(&ctx.accounts.authority.to_account_info())
+        .update_authority(&ctx.accounts.compounding_vault.to_account_info(), true)
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .rent(Some(&ctx.accounts.rent.to_account_info()))
+        .data(DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(false)
+        .invoke_signed(signer)?;
+
+    let compounding_vault = &mut ctx.accounts.compounding_vault;
+    compounding_vault.receipt_mint = Some(ctx.accounts.receipt_mint.key());
+
+    emit!(LiquidStakingEnabled {
+        pool: pool_key,
+        vault: compounding_vault.key(),
+        receipt_mint: ctx.accounts.receipt_mint.key(),
+        timestamp: current_time,
+    });
+
+    msg!("Liquid staking receipt {} enabled for pool {}", ctx.accounts.receipt_mint.key(), pool_key);
+
+    Ok(())
+}
+
+pub fn stake_liquid(ctx: Context<StakeLiquid>, amount: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(ctx.accounts.staking_pool.is_active, CustomError::StakingPoolInactive);
+    require!(
+        amount >= ctx.accounts.staking_pool.min_stake_amount,
+        CustomError::InsufficientStakeAmount
+    );
+
+    // Fold accrued rewards in first so the receipt is priced fairly
+    let multiplier_bps = ctx.accounts.staking_tier_config.multiplier_for(StakeTier::Flexible);
+    compound_position(
+        &mut ctx.accounts.staking_pool,
+        &mut ctx.accounts.vault_position,
+        &ctx.accounts.reward_vault,
+        &ctx.accounts.stake_vault,
+        &ctx.accounts.token_program,
+        multiplier_bps,
+        current_time,
+        0,
+    )?;
+
+    let assets = ctx.accounts.vault_position.amount;
+    require!(
+        assets > 0 || ctx.accounts.compounding_vault.total_shares == 0,
+        CustomError::VaultHasNoAssets
+    );
+    let receipt_amount = ctx.accounts.compounding_vault
+        .shares_for_deposit(amount, assets)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(receipt_amount > 0, CustomError::InvalidAmount);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let new_amount = assets.checked_add(amount).ok_or(CustomError::MathOverflow)?;
+    ctx.accounts.vault_position
+        .set_amount(&mut ctx.accounts.staking_pool, new_amount, multiplier_bps)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let pool_key = ctx.accounts.staking_pool.key();
+    let seeds = &[b"compounding_vault".as_ref(), pool_key.as_ref(), &[ctx.accounts.compounding_vault.bump]];
+    let signer = &[&seeds[..]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.receipt_mint.to_account_info(),
+                to: ctx.accounts.receipt_token_account.to_account_info(),
+                authority: ctx.accounts.compounding_vault.to_account_info(),
+            },
+            signer,
+        ),
+        receipt_amount,
+    )?;
+
+    let compounding_vault = &mut ctx.accounts.compounding_vault;
+    compounding_vault.total_shares = compounding_vault.total_shares
+        .checked_add(receipt_amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(ReceiptTokensMinted {
+        pool: pool_key,
+        owner: ctx.accounts.user.key(),
+        amount,
+        receipt_amount,
+        timestamp: current_time,
+    });
+
+    msg!("Staked {} tokens for {} receipt tokens", amount, receipt_amount);
+
+    Ok(())
+}
@@ -0,0 +1,430 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, MintTo};
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeInflationSchedule<'info> {
+    #[account(
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump,
+        has_one = mint,
+        constraint = token_metadata.creator == creator.key() @ CustomError::Unauthorized
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + InflationSchedule::LEN,
+        seeds = [b"inflation_schedule", mint.key().as_ref()],
+        bump
+    )]
+    pub inflation_schedule: Account<'info, InflationSchedule>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_inflation_schedule(
+    ctx: Context<InitializeInflationSchedule>,
+    max_supply: u64,
+    period_seconds: i64,
+    max_inflation_bps_per_period: u16,
+) -> Result<()> {
+    let token_metadata = &ctx.accounts.token_metadata;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Only tokens launched with a program-owned mint authority can inflate
+    require!(!token_metadata.is_fixed_supply, CustomError::FixedSupply);
+
+    // Validate schedule parameters
+    require!(max_supply > ctx.accounts.mint.supply, CustomError::InvalidInflationSchedule);
+    require!(period_seconds >= MIN_INFLATION_PERIOD, CustomError::InvalidInflationSchedule);
+    require!(
+        max_inflation_bps_per_period > 0
+            && max_inflation_bps_per_period <= MAX_INFLATION_BPS_PER_PERIOD,
+        CustomError::InvalidInflationSchedule
+    );
+
+    let schedule = &mut ctx.accounts.inflation_schedule;
+    schedule.mint = ctx.accounts.mint.key();
+    schedule.max_supply = max_supply;
+    schedule.period_seconds = period_seconds;
+    schedule.max_inflation_bps_per_period = max_inflation_bps_per_period;
+    schedule.period_start = current_time;
+    schedule.period_start_supply = ctx.accounts.mint.supply;
+    schedule.minted_in_period = 0;
+    schedule.total_minted = 0;
+    schedule.last_mint_time = 0;
+    schedule.bump = *ctx.bumps.get("inflation_schedule").unwrap();
+    schedule.proposal_count = 0;
+
+    msg!("Inflation schedule initialized for mint {}", schedule.mint);
+    msg!("Max supply: {}, Period: {}s, Cap: {} bps", max_supply, period_seconds, max_inflation_bps_per_period);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeMint<'info> {
+    #[account(
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump,
+        has_one = mint
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"inflation_schedule", mint.key().as_ref()],
+        bump = inflation_schedule.bump,
+        has_one = mint
+    )]
+    pub inflation_schedule: Account<'info, InflationSchedule>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"staking_pool", mint.key().as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Only the token's own stakers can open a mint proposal
+    #[account(
+        seeds = [b"staking_position", staking_pool.key().as_ref(), proposer.key().as_ref(), &[proposer_position.tier as u8]],
+        bump = proposer_position.bump,
+        constraint = proposer_position.owner == proposer.key() @ CustomError::Unauthorized
+    )]
+    pub proposer_position: Account<'info, StakingPosition>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + MintProposal::LEN,
+        seeds = [
+            b"mint_proposal",
+            mint.key().as_ref(),
+            &inflation_schedule.proposal_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub mint_proposal: Account<'info, MintProposal>,
+
+    #[account(
+        token::mint = mint
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_mint(ctx: Context<ProposeMint>, amount: u64) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let staking_pool = &ctx.accounts.staking_pool;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(!ctx.accounts.token_metadata.is_fixed_supply, CustomError::FixedSupply);
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+    require!(
+        ctx.accounts.proposer_position.amount >= staking_pool.min_stake_amount,
+        CustomError::InsufficientVotingPower
+    );
+
+    // Quorum is fixed against the token's stake when voting opens
+    let quorum_votes = (staking_pool.total_staked as u128)
+        .checked_mul(MINT_PROPOSAL_QUORUM_BPS as u128)
+        .ok_or(CustomError::MathOverflow)?
+        / 10_000;
+
+    let schedule = &mut ctx.accounts.inflation_schedule;
+    let proposal = &mut ctx.accounts.mint_proposal;
+    proposal.mint = ctx.accounts.mint.key();
+    proposal.id = schedule.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.amount = amount;
+    proposal.recipient = ctx.accounts.recipient_token_account.key();
+    proposal.yes_votes = 0;
+    proposal.no_votes = 0;
+    proposal.quorum_votes = quorum_votes.max(1) as u64;
+    proposal.start_time = current_time;
+    proposal.end_time = current_time + governance_config.voting_period;
+    proposal.executed = false;
+    proposal.executed_at = None;
+    proposal.bump = *ctx.bumps.get("mint_proposal").unwrap();
+
+    schedule.proposal_count = schedule.proposal_count
+        .checked_add(1)
+        .ok_or(CustomError::MathOverflow)?;
+
+    msg!("Mint proposal {} opened for {} tokens of {}", proposal.id, amount, proposal.mint);
+    msg!("Voting ends at {}, quorum {}", proposal.end_time, proposal.quorum_votes);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct VoteMintProposal<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_proposal", mint_proposal.mint.as_ref(), &mint_proposal.id.to_le_bytes()],
+        bump = mint_proposal.bump
+    )]
+    pub mint_proposal: Account<'info, MintProposal>,
+
+    #[account(
+        seeds = [b"staking_pool", mint_proposal.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_position", staking_pool.key().as_ref(), voter.key().as_ref(), &[staking_position.tier as u8]],
+        bump = staking_position.bump,
+        constraint = staking_position.owner == voter.key() @ CustomError::Unauthorized
+    )]
+    pub staking_position: Account<'info, StakingPosition>,
+
+    /// One vote per staking position per proposal
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + MintVote::LEN,
+        seeds = [b"mint_vote", mint_proposal.key().as_ref(), staking_position.key().as_ref()],
+        bump
+    )]
+    pub mint_vote: Account<'info, MintVote>,
+
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn vote_mint_proposal(ctx: Context<VoteMintProposal>, approve: bool) -> Result<()> {
+    let staking_position = &mut ctx.accounts.staking_position;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let proposal = &mut ctx.accounts.mint_proposal;
+    require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(current_time <= proposal.end_time, CustomError::VotingPeriodEnded);
+
+    // Only stake that predates the proposal counts, so tokens moved between
+    // wallets and restaked mid-vote cannot be counted twice
+    require!(staking_position.amount > 0, CustomError::InsufficientVotingPower);
+    require!(
+        staking_position.last_deposit_time < proposal.start_time,
+        CustomError::StakeTooRecent
+    );
+
+    // The weight stays staked until voting closes, so it cannot be withdrawn
+    // after being counted
+    let weight = staking_position.amount;
+    staking_position.vote_locked_until = staking_position.vote_locked_until.max(proposal.end_time);
+    if approve {
+        proposal.yes_votes = proposal.yes_votes
+            .checked_add(weight)
+            .ok_or(CustomError::MathOverflow)?;
+    } else {
+        proposal.no_votes = proposal.no_votes
+            .checked_add(weight)
+            .ok_or(CustomError::MathOverflow)?;
+    }
+
+    let mint_vote = &mut ctx.accounts.mint_vote;
+    mint_vote.proposal = proposal.key();
+    mint_vote.position = staking_position.key();
+    mint_vote.voter = ctx.accounts.voter.key();
+    mint_vote.weight = weight;
+    mint_vote.approve = approve;
+    mint_vote.voted_at = current_time;
+    mint_vote.bump = *ctx.bumps.get("mint_vote").unwrap();
+
+    msg!("Voted {} on mint proposal {} with weight {}", if approve { "yes" } else { "no" }, proposal.id, weight);
+    msg!("Yes: {}, No: {}", proposal.yes_votes, proposal.no_votes);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MintTokens<'info> {
+    #[account(
+        mut,
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump,
+        has_one = mint
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"inflation_schedule", mint.key().as_ref()],
+        bump = inflation_schedule.bump,
+        has_one = mint
+    )]
+    pub inflation_schedule: Account<'info, InflationSchedule>,
+
+    #[account(mut)]
+    pub mint: Account<'info, Mint>,
+
+    /// CHECK: Program-owned mint authority PDA
+    #[account(
+        seeds = [b"mint_authority", mint.key().as_ref()],
+        bump
+    )]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        token::mint = mint
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_proposal", mint.key().as_ref(), &mint_proposal.id.to_le_bytes()],
+        bump = mint_proposal.bump,
+        has_one = mint
+    )]
+    pub mint_proposal: Account<'info, MintProposal>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct TokensMinted {
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub proposal_id: u64,
+    pub new_total_supply: u64,
+    pub timestamp: i64,
+}
+
+pub fn handler(ctx: Context<MintTokens>, amount: u64) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let proposal = &ctx.accounts.mint_proposal;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(!ctx.accounts.token_metadata.is_fixed_supply, CustomError::FixedSupply);
+
+    // Proposal must have passed and cleared the execution delay
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+    require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(proposal.has_passed(current_time), CustomError::ProposalNotPassed);
+    require!(
+        current_time >= proposal.end_time + governance_config.execution_delay,
+        CustomError::ProposalNotPassed
+    );
+
+    // Proposal must authorize exactly this amount and recipient; with both pinned,
+    // anyone may execute a passed proposal
+    require!(proposal.amount == amount, CustomError::ProposalMismatch);
+    require!(
+        proposal.recipient == ctx.accounts.recipient_token_account.key(),
+        CustomError::ProposalMismatch
+    );
+
+    // Roll over to a new inflation period if the current one has elapsed
+    let schedule = &mut ctx.accounts.inflation_schedule;
+    let current_supply = ctx.accounts.mint.supply;
+    if current_time >= schedule.period_start + schedule.period_seconds {
+        schedule.period_start = current_time;
+        schedule.period_start_supply = current_supply;
+        schedule.minted_in_period = 0;
+    }
+
+    // Enforce per-period inflation cap
+    let period_cap = (schedule.period_start_supply as u128)
+        .checked_mul(schedule.max_inflation_bps_per_period as u128)
+        .ok_or(CustomError::MathOverflow)?
+        / 10_000;
+    let minted_in_period = schedule.minted_in_period
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(minted_in_period as u128 <= period_cap, CustomError::InflationCapExceeded);
+
+    // Enforce hard supply cap
+    let new_supply = current_supply
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(new_supply <= schedule.max_supply, CustomError::InflationCapExceeded);
+
+    // Mint with the program-owned authority
+    let mint_key = ctx.accounts.mint.key();
+    let seeds = &[
+        b"mint_authority".as_ref(),
+        mint_key.as_ref(),
+        &[*ctx.bumps.get("mint_authority").unwrap()],
+    ];
+    let signer = &[&seeds[..]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    schedule.minted_in_period = minted_in_period;
+    schedule.total_minted = schedule.total_minted
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    schedule.last_mint_time = current_time;
+
+    // Update token supply figures
+    let token_metadata = &mut ctx.accounts.token_metadata;
+    token_metadata.total_supply = token_metadata.total_supply
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    token_metadata.circulating_supply = token_metadata.circulating_supply
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    // Mark proposal as executed
+    let proposal = &mut ctx.accounts.mint_proposal;
+    proposal.executed = true;
+    proposal.executed_at = Some(current_time);
+
+    emit!(TokensMinted {
+        mint: mint_key,
+        recipient: ctx.accounts.recipient_token_account.key(),
+        amount,
+        proposal_id: proposal.id,
+        new_total_supply: token_metadata.total_supply,
+        timestamp: current_time,
+    });
+
+    msg!("Minted {} tokens under proposal {}", amount, proposal.id);
+    msg!("New total supply: {}", token_metadata.total_supply);
+
+    Ok(())
+}
@@ -1,12 +1,29 @@
 pub mod initialize;
 pub mod register_creator;
+pub mod creator_stake;
 pub mod create_token;
+pub mod create_beneficiary_vesting;
+pub mod mint_tokens;
+pub mod create_launch_pass;
+pub mod symbol_registry;
+pub mod bonding_curve;
+pub mod graduate;
 pub mod claim_vested;
+pub mod revoke_vesting;
+pub mod vesting_position;
 pub mod transfer_tokens;
 pub mod stake_tokens;
+pub mod staking_vaults;
+pub mod staking_tiers;
+pub mod unstake_tokens;
+pub mod claim_rewards;
+pub mod compounding_vault;
+pub mod liquid_staking;
+pub mod fee_revenue;
 pub mod choose_vesting_option;
+pub mod holder_distribution;
 pub mod collect_trading_fee;
-pub mod validate_creator_limits;
+pub mod creator_policy;
 pub mod create_lbm_pool;
 pub mod participate_lbm;
 pub mod finalize_lbm;
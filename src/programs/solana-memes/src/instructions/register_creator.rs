@@ -8,19 +8,30 @@ pub struct RegisterCreator<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
     
-    /// CHECK: Treasury account (safe, just receives SOL)
-    #[account(mut)]
-    pub treasury: AccountInfo<'info>,
+    /// Per-creator escrow holding the stake until it is withdrawn or slashed
+    #[account(
+        mut,
+        seeds = [b"creator_stake", creator.key().as_ref()],
+        bump
+    )]
+    pub stake_escrow: SystemAccount<'info>,
     
+    /// Deregistered creators keep their profile, so registering again reuses it
     #[account(
-        init,
+        init_if_needed,
         payer = creator,
-        space = 8 + 1 + 32 + 8 + 8 + 4 + 4 + 4 + 8 + 1 + 200 + 1 + 32
+        space = 8 + CreatorProfile::LEN,
+        seeds = [b"creator_profile", creator.key().as_ref()],
+        bump
     )]
     pub creator_profile: Account<'info, CreatorProfile>,
     
-    /// CHECK: Launch pass mint (optional)
-    pub launch_pass_mint: Option<AccountInfo<'info>>,
+    /// Launch pass to bind for the first launch (optional; can be bound later)
+    #[account(
+        seeds = [b"launch_pass", launch_pass.mint.as_ref()],
+        bump = launch_pass.bump
+    )]
+    pub launch_pass: Option<Account<'info, LaunchPass>>,
     
     pub system_program: Program<'info, System>,
 }
@@ -31,38 +42,49 @@ pub fn handler(ctx: Context<RegisterCreator>, stake_amount: u64) -> Result<()> {
 
     // Ensure not already registered
     require!(!creator.is_registered, CustomError::AlreadyRegistered);
+    require!(!creator.is_banned, CustomError::CreatorBanned);
 
     // Check minimum stake amount
     require!(stake_amount >= MIN_STAKE_AMOUNT, CustomError::InsufficientStake);
 
-    // Transfer stake (in SOL lamports)
+    if let Some(launch_pass) = &ctx.accounts.launch_pass {
+        require!(!launch_pass.is_used, CustomError::LaunchPassUsed);
+    }
+
+    // Escrow stake (in SOL lamports)
     let ix = anchor_lang::solana_program::system_instruction::transfer(
         &ctx.accounts.creator.key(),
-        &ctx.accounts.treasury.key(),
+        &ctx.accounts.stake_escrow.key(),
         stake_amount,
     );
     anchor_lang::solana_program::program::invoke(
         &ix,
         &[
             ctx.accounts.creator.to_account_info(),
-            ctx.accounts.treasury.to_account_info(),
+            ctx.accounts.stake_escrow.to_account_info(),
         ],
     )?;
 
-    // Initialize creator profile
+    // Returning creators pick up their reputation, tier and penalties where they left off
+    if creator.owner == Pubkey::default() {
+        creator.owner = ctx.accounts.creator.key();
+        creator.last_creation_ts = 0;
+        creator.reputation_score = 0;
+        creator.total_tokens_created = 0;
+        creator.successful_tokens = 0;
+        creator.failed_tokens = 0;
+        creator.total_volume = 0;
+        creator.is_banned = false;
+        creator.ban_reason = "".to_string();
+        creator.bump = *ctx.bumps.get("creator_profile").unwrap();
+    }
     creator.is_registered = true;
-    creator.owner = ctx.accounts.creator.key();
     creator.stake_amount = stake_amount;
-    creator.last_creation_ts = 0;
-    creator.reputation_score = 0;
-    creator.total_tokens_created = 0;
-    creator.successful_tokens = 0;
-    creator.failed_tokens = 0;
-    creator.total_volume = 0;
-    creator.is_banned = false;
-    creator.ban_reason = "".to_string();
-    creator.launch_pass_required = ctx.accounts.launch_pass_mint.is_some();
-    creator.launch_pass_mint = ctx.accounts.launch_pass_mint.as_ref().map(|mint| mint.key());
+    creator.launch_pass_required = true;
+    if let Some(launch_pass) = &ctx.accounts.launch_pass {
+        creator.launch_pass_mint = Some(launch_pass.mint);
+    }
+    creator.deregistration_requested_at = None;
 
     msg!(
         "Creator registered successfully with {} SOL stake",
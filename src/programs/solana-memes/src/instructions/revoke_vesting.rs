@@ -0,0 +1,253 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct RevokeVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting.mint.as_ref(), vesting.beneficiary.as_ref()],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vesting
+    )]
+    pub vesting_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = vesting.mint @ CustomError::InvalidAccount
+    )]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"token_metadata", mint.key().as_ref()],
+        bump = token_metadata.bump
+    )]
+    pub token_metadata: Account<'info, TokenMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"staking_pool", mint.key().as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Receives the remainder for `StakingPool` revocations
+    #[account(
+        mut,
+        seeds = [b"reward_vault", mint.key().as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Option<Account<'info, TokenAccount>>,
+
+    /// Receives the remainder for `Treasury` revocations; owned by the treasury authority
+    #[account(
+        mut,
+        token::mint = mint
+    )]
+    pub destination_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    /// Passed `VestingRevocation` proposal targeting this vesting
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Option<Account<'info, GovernanceProposal>>,
+
+    /// Resolved alert against the vesting owner or token creator
+    #[account(
+        seeds = [b"suspicious_alert", alert.alert_id.checked_sub(1).ok_or(CustomError::InvalidAccount)?.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub alert: Option<Account<'info, SuspiciousActivityAlert>>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct VestingRevoked {
+    pub vesting: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub destination: RevocationDestination,
+    pub proposal_id: Option<u64>,
+    pub alert_id: Option<u64>,
+    pub timestamp: i64,
+}
+
+pub fn handler(ctx: Context<RevokeVesting>, destination: RevocationDestination) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let vesting = &ctx.accounts.vesting;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(vesting.is_revocable, CustomError::VestingNotRevocable);
+    require!(!vesting.revoked, CustomError::VestingRevoked);
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+
+    // Revocation needs either a passed proposal or a confirmed alert
+    let mut proposal_id = None;
+    let mut alert_id = None;
+    if let Some(proposal) = &ctx.accounts.proposal {
+        require!(!proposal.executed, CustomError::ProposalAlreadyExecuted);
+        require!(proposal.has_passed(current_time), CustomError::ProposalNotPassed);
+        require!(
+            current_time >= proposal.end_time + governance_config.execution_delay,
+            CustomError::ProposalNotPassed
+        );
+        match &proposal.proposal_type {
+            ProposalType::VestingRevocation { vesting: target, destination: proposed } => {
+                require!(
+                    *target == vesting.key() && *proposed == destination,
+                    CustomError::ProposalMismatch
+                );
+            },
+            _ => return err!(CustomError::ProposalMismatch),
+        }
+        proposal_id = Some(proposal.id);
+    } else if let Some(alert) = &ctx.accounts.alert {
+        require!(
+            ctx.accounts.authority.key() == governance_config.authority,
+            CustomError::Unauthorized
+        );
+        require!(
+            alert.status == AlertStatus::Resolved
+                && matches!(
+                    alert.resolution,
+                    Some(AlertResolution::Penalty) | Some(AlertResolution::Ban)
+                ),
+            CustomError::RevocationNotAuthorized
+        );
+        require!(
+            alert.target_wallet == vesting.owner
+                || alert.target_wallet == ctx.accounts.token_metadata.creator,
+            CustomError::RevocationNotAuthorized
+        );
+        alert_id = Some(alert.alert_id);
+    } else {
+        return err!(CustomError::RevocationNotAuthorized);
+    }
+
+    // Only the unvested remainder is forfeited; vested tokens stay claimable
+    let amount = vesting.amount
+        .checked_sub(vesting.vested_amount(current_time))
+        .ok_or(CustomError::MathOverflow)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    let owner_key = vesting.owner;
+    let beneficiary_key = vesting.beneficiary;
+    let seeds = &[b"vesting".as_ref(), mint_key.as_ref(), beneficiary_key.as_ref(), &[vesting.bump]];
+    let signer = &[&seeds[..]];
+
+    if amount > 0 {
+        match destination {
+            RevocationDestination::Burn => {
+                token::burn(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Burn {
+                            mint: ctx.accounts.mint.to_account_info(),
+                            from: ctx.accounts.vesting_token_account.to_account_info(),
+                            authority: ctx.accounts.vesting.to_account_info(),
+                        },
+                        signer,
+                    ),
+                    amount,
+                )?;
+
+                let token_metadata = &mut ctx.accounts.token_metadata;
+                token_metadata.total_supply = token_metadata.total_supply
+                    .checked_sub(amount)
+                    .ok_or(CustomError::MathOverflow)?;
+            },
+            RevocationDestination::StakingPool | RevocationDestination::Treasury => {
+                let destination_account = if destination == RevocationDestination::StakingPool {
+                    ctx.accounts.reward_vault.as_ref().ok_or(CustomError::InvalidAccount)?
+                } else {
+                    let treasury_account = ctx.accounts.destination_token_account
+                        .as_ref()
+                        .ok_or(CustomError::InvalidAccount)?;
+                    require!(
+                        treasury_account.owner == ctx.accounts.platform_config.authority,
+                        CustomError::InvalidAccount
+                    );
+                    treasury_account
+                };
+
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.vesting_token_account.to_account_info(),
+                            to: destination_account.to_account_info(),
+                            authority: ctx.accounts.vesting.to_account_info(),
+                        },
+                        signer,
+                    ),
+                    amount,
+                )?;
+
+                // Forfeited tokens are paid out to stakers through the reward accumulator
+                if destination == RevocationDestination::StakingPool {
+                    let staking_pool = &mut ctx.accounts.staking_pool;
+                    staking_pool.update_rewards(current_time).ok_or(CustomError::MathOverflow)?;
+                    staking_pool.total_rewards = staking_pool.total_rewards
+                        .checked_add(amount)
+                        .ok_or(CustomError::MathOverflow)?;
+                    staking_pool.undistributed_rewards = staking_pool.undistributed_rewards
+                        .checked_add(amount)
+                        .ok_or(CustomError::MathOverflow)?;
+                }
+            },
+        }
+    }
+
+    // Freeze the schedule at the revocation time
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.revoked = true;
+    vesting.revoke_time = Some(current_time);
+
+    if let Some(proposal) = &mut ctx.accounts.proposal {
+        proposal.executed = true;
+        proposal.executed_at = Some(current_time);
+        proposal.executed_by = Some(ctx.accounts.authority.key());
+        proposal.updated_at = current_time;
+    }
+
+    emit!(VestingRevoked {
+        vesting: vesting.key(),
+        mint: mint_key,
+        owner: owner_key,
+        amount,
+        destination,
+        proposal_id,
+        alert_id,
+        timestamp: current_time,
+    });
+
+    msg!("Vesting {} revoked: {} tokens sent to {:?}", vesting.key(), amount, destination);
+
+    Ok(())
+}
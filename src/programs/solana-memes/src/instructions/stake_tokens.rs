@@ -1,33 +1,64 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 use crate::state::*;
+use crate::phase_1_3_structures::AdvancedStaking;
 use crate::errors::CustomError;
 
 #[derive(Accounts)]
+#[instruction(amount: u64, tier: StakeTier)]
 pub struct StakeTokens<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
     pub staking_pool: Account<'info, StakingPool>,
-    
+
     #[account(
         init_if_needed,
         payer = user,
-        space = 8 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1
+        space = 8 + StakingPosition::LEN,
+        seeds = [b"staking_position", staking_pool.key().as_ref(), user.key().as_ref(), &[tier as u8]],
+        bump
     )]
     pub staking_position: Account<'info, StakingPosition>,
-    
-    #[account(mut)]
+
+    #[account(
+        seeds = [b"staking_tiers"],
+        bump = staking_tier_config.bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    /// User's staking summary, kept in step with their tiered stakes when provided
+    #[account(
+        mut,
+        seeds = [b"advanced_staking", user.key().as_ref()],
+        bump
+    )]
+    pub advanced_staking: Option<Account<'info, AdvancedStaking>>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = user
+    )]
     pub user_token_account: Account<'info, TokenAccount>,
-    
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.stake_vault_bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
     #[account(mut)]
-    pub pool_token_account: Account<'info, TokenAccount>,
-    
     pub user: Signer<'info>,
-    
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(ctx: Context<StakeTokens>, amount: u64) -> Result<()> {
+pub fn handler(ctx: Context<StakeTokens>, amount: u64, tier: StakeTier) -> Result<()> {
     let staking_pool = &mut ctx.accounts.staking_pool;
     let staking_position = &mut ctx.accounts.staking_position;
     let clock = Clock::get()?;
@@ -39,43 +70,81 @@ pub fn handler(ctx: Context<StakeTokens>, amount: u64) -> Result<()> {
     require!(amount >= staking_pool.min_stake_amount, CustomError::InsufficientStakeAmount);
     require!(amount <= staking_pool.max_stake_amount, CustomError::ExceedsMaxStakeAmount);
 
+    // Accrue pool rewards before the stake changes
+    staking_pool.update_rewards(clock.unix_timestamp).ok_or(CustomError::MathOverflow)?;
+
     // Initialize staking position if new
     if staking_position.owner == Pubkey::default() {
         staking_position.owner = ctx.accounts.user.key();
         staking_position.pool = staking_pool.key();
         staking_position.amount = 0;
         staking_position.start_time = clock.unix_timestamp;
-        staking_position.end_time = clock.unix_timestamp + staking_pool.lock_period;
+        staking_position.end_time = clock.unix_timestamp;
         staking_position.rewards_claimed = 0;
         staking_position.pending_rewards = 0;
-        staking_position.is_locked = true;
+        staking_position.is_locked = false;
+        staking_position.reward_per_token_paid = staking_pool.reward_per_token_stored;
+        staking_position.bump = *ctx.bumps.get("staking_position").unwrap();
+        staking_position.tier = tier;
+        staking_position.weighted_amount = 0;
+        staking_position.fee_revenue_per_token_paid = staking_pool.fee_revenue_per_token_stored;
+        staking_position.pending_fee_revenue = 0;
+        staking_position.fee_exempt = false;
+        staking_position.vote_locked_until = 0;
     }
 
+    // Bank rewards earned on the existing stake
+    staking_position.settle_rewards(staking_pool).ok_or(CustomError::MathOverflow)?;
+
     // Transfer tokens to pool
     token::transfer(
         CpiContext::new(
             ctx.accounts.token_program.to_account_info(),
             token::Transfer {
                 from: ctx.accounts.user_token_account.to_account_info(),
-                to: ctx.accounts.pool_token_account.to_account_info(),
+                to: ctx.accounts.stake_vault.to_account_info(),
                 authority: ctx.accounts.user.to_account_info(),
             },
         ),
         amount,
     )?;
 
-    // Update staking position
-    staking_position.amount += amount;
-    staking_position.end_time = clock.unix_timestamp + staking_pool.lock_period;
+    // Update staking position and pool totals at the tier's current multiplier
+    let new_amount = staking_position.amount
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    let multiplier_bps = ctx.accounts.staking_tier_config.multiplier_for(tier);
+    staking_position
+        .set_amount(staking_pool, new_amount, multiplier_bps)
+        .ok_or(CustomError::MathOverflow)?;
 
-    // Update pool
-    staking_pool.total_staked += amount;
-    staking_pool.last_update_time = clock.unix_timestamp;
+    // Fresh deposits cannot vote on mint proposals that are already open
+    staking_position.last_deposit_time = clock.unix_timestamp;
+
+    // Adding to a locked tier restarts its lock
+    let lock_seconds = tier.lock_seconds();
+    staking_position.is_locked = lock_seconds > 0;
+    staking_position.end_time = clock.unix_timestamp + lock_seconds;
+
+    if let Some(advanced_staking) = &mut ctx.accounts.advanced_staking {
+        if tier == StakeTier::Flexible {
+            advanced_staking.total_flexible_staked = advanced_staking.total_flexible_staked
+                .checked_add(amount)
+                .ok_or(CustomError::MathOverflow)?;
+        } else {
+            advanced_staking.total_locked_staked = advanced_staking.total_locked_staked
+                .checked_add(amount)
+                .ok_or(CustomError::MathOverflow)?;
+        }
+        advanced_staking.updated_at = clock.unix_timestamp;
+    }
 
     msg!(
-        "Staked {} tokens. Total staked: {}",
+        "Staked {} tokens in {:?} tier. Total staked: {} (weighted {})",
         amount,
-        staking_position.amount
+        tier,
+        staking_position.amount,
+        staking_position.weighted_amount
     );
 
     Ok(())
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeStakingTiers<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StakingTierConfig::LEN,
+        seeds = [b"staking_tiers"],
+        bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateStakingTiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_tiers"],
+        bump = staking_tier_config.bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct StakingTiersUpdated {
+    pub multipliers_bps: [u16; StakeTier::COUNT],
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+pub fn initialize_staking_tiers(ctx: Context<InitializeStakingTiers>) -> Result<()> {
+    let staking_tier_config = &mut ctx.accounts.staking_tier_config;
+    staking_tier_config.multipliers_bps = StakingTierConfig::default_multipliers();
+    staking_tier_config.updated_at = Clock::get()?.unix_timestamp;
+    staking_tier_config.bump = *ctx.bumps.get("staking_tier_config").unwrap();
+
+    msg!("Staking tiers initialized: {:?}", staking_tier_config.multipliers_bps);
+
+    Ok(())
+}
+
+pub fn update_staking_tiers(ctx: Context<UpdateStakingTiers>) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Proposal must have passed and cleared the execution delay
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+    require!(!ctx.accounts.proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(ctx.accounts.proposal.has_passed(current_time), CustomError::ProposalNotPassed);
+    require!(
+        current_time >= ctx.accounts.proposal.end_time + governance_config.execution_delay,
+        CustomError::ProposalNotPassed
+    );
+
+    let multipliers_bps = match &ctx.accounts.proposal.proposal_type {
+        ProposalType::StakingTierUpdate { multipliers_bps } => *multipliers_bps,
+        _ => return err!(CustomError::ProposalMismatch),
+    };
+    require!(
+        StakingTierConfig::validate_multipliers(&multipliers_bps),
+        CustomError::InvalidStakingTiers
+    );
+
+    // Existing stakes pick up the new multiplier on their next stake or unstake
+    let staking_tier_config = &mut ctx.accounts.staking_tier_config;
+    staking_tier_config.multipliers_bps = multipliers_bps;
+    staking_tier_config.updated_at = current_time;
+
+    // Mark proposal as executed
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed = true;
+    proposal.executed_at = Some(current_time);
+    proposal.executed_by = Some(ctx.accounts.authority.key());
+    proposal.updated_at = current_time;
+
+    emit!(StakingTiersUpdated {
+        multipliers_bps,
+        proposal_id: proposal.id,
+        timestamp: current_time,
+    });
+
+    msg!("Staking tiers updated under proposal {}", proposal.id);
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeStakingVaults<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", mint.key().as_ref()],
+        bump = staking_pool.bump,
+        has_one = mint
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"reward_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = staking_pool
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Holds staked principal; only the pool PDA can move it out
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"stake_vault", mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = staking_pool
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct FundRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = funder
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    pub funder: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct RewardsFunded {
+    pub pool: Pubkey,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub undistributed_rewards: u64,
+    pub timestamp: i64,
+}
+
+pub fn initialize_staking_vaults(ctx: Context<InitializeStakingVaults>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.reward_vault_bump = *ctx.bumps.get("reward_vault").unwrap();
+    staking_pool.stake_vault_bump = *ctx.bumps.get("stake_vault").unwrap();
+
+    msg!("Stake and reward vaults initialized for staking pool {}", staking_pool.key());
+
+    Ok(())
+}
+
+pub fn fund_rewards(ctx: Context<FundRewards>, amount: u64) -> Result<()> {
+    require!(amount > 0, CustomError::InvalidAmount);
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Accrue at the old balance before the new rewards become available
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.update_rewards(current_time).ok_or(CustomError::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                to: ctx.accounts.reward_vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    staking_pool.total_rewards = staking_pool.total_rewards
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+    staking_pool.undistributed_rewards = staking_pool.undistributed_rewards
+        .checked_add(amount)
+        .ok_or(CustomError::MathOverflow)?;
+
+    emit!(RewardsFunded {
+        pool: staking_pool.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        undistributed_rewards: staking_pool.undistributed_rewards,
+        timestamp: current_time,
+    });
+
+    msg!("Funded {} reward tokens; {} undistributed", amount, staking_pool.undistributed_rewards);
+
+    Ok(())
+}
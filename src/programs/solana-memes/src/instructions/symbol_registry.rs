@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+#[instruction(normalized_symbol: String)]
+pub struct ReserveSymbol<'info> {
+    /// Any verified token launched by the creator qualifies them for reservations
+    #[account(
+        seeds = [b"token_metadata", verified_token.mint.as_ref()],
+        bump = verified_token.bump,
+        constraint = verified_token.creator == creator.key() @ CustomError::Unauthorized,
+        constraint = verified_token.is_verified @ CustomError::Unauthorized
+    )]
+    pub verified_token: Account<'info, TokenMetadata>,
+
+    /// Expired reservations can be taken over, so the entry may already exist
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + SymbolRegistry::LEN,
+        seeds = [b"symbol_registry", normalized_symbol.as_bytes()],
+        bump
+    )]
+    pub symbol_registry: Account<'info, SymbolRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + SymbolReservationQuota::LEN,
+        seeds = [b"symbol_reservation_quota", creator.key().as_ref()],
+        bump
+    )]
+    pub reservation_quota: Account<'info, SymbolReservationQuota>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseSymbol<'info> {
+    #[account(
+        mut,
+        seeds = [b"symbol_registry", symbol_registry.normalized_symbol.as_bytes()],
+        bump = symbol_registry.bump,
+        close = registrant
+    )]
+    pub symbol_registry: Account<'info, SymbolRegistry>,
+
+    /// CHECK: Original registrant, refunded the registry rent
+    #[account(
+        mut,
+        address = symbol_registry.registered_by @ CustomError::InvalidAccount
+    )]
+    pub registrant: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct SymbolReleased {
+    pub normalized_symbol: String,
+    pub mint: Pubkey,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+pub fn reserve_symbol(ctx: Context<ReserveSymbol>, normalized_symbol: String) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Seeds must be the canonical form so the reservation blocks every look-alike
+    require!(
+        !normalized_symbol.is_empty()
+            && normalized_symbol.len() <= mpl_token_metadata::MAX_SYMBOL_LENGTH
+            && normalized_symbol == SymbolRegistry::normalize(&normalized_symbol),
+        CustomError::InvalidSymbol
+    );
+
+    // Launched symbols stay taken; live reservations block everyone, including a renewal
+    let symbol_registry = &mut ctx.accounts.symbol_registry;
+    require!(symbol_registry.mint == Pubkey::default(), CustomError::SymbolTaken);
+    require!(
+        symbol_registry.registered_by == Pubkey::default()
+            || current_time >= symbol_registry.reserved_until,
+        CustomError::SymbolReserved
+    );
+
+    let reservation_quota = &mut ctx.accounts.reservation_quota;
+    if reservation_quota.creator == Pubkey::default() {
+        reservation_quota.creator = ctx.accounts.creator.key();
+        reservation_quota.bump = *ctx.bumps.get("reservation_quota").unwrap();
+    }
+    if current_time - reservation_quota.period_start >= SYMBOL_RESERVATION_PERIOD {
+        reservation_quota.period_start = current_time;
+        reservation_quota.reservations = 0;
+    }
+    require!(
+        reservation_quota.reservations < MAX_SYMBOL_RESERVATIONS_PER_PERIOD,
+        CustomError::SymbolReservationLimitReached
+    );
+    reservation_quota.reservations += 1;
+
+    symbol_registry.normalized_symbol = normalized_symbol;
+    symbol_registry.mint = Pubkey::default();
+    symbol_registry.registered_by = ctx.accounts.creator.key();
+    symbol_registry.registered_at = current_time;
+    symbol_registry.reserved_until = current_time + SYMBOL_RESERVATION_PERIOD;
+    symbol_registry.bump = *ctx.bumps.get("symbol_registry").unwrap();
+
+    msg!(
+        "Symbol {} reserved by {} until {}",
+        symbol_registry.normalized_symbol,
+        symbol_registry.registered_by,
+        symbol_registry.reserved_until
+    );
+
+    Ok(())
+}
+
+pub fn release_symbol(ctx: Context<ReleaseSymbol>) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let symbol_registry = &ctx.accounts.symbol_registry;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Proposal must have passed and cleared the execution delay
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+    require!(!ctx.accounts.proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(ctx.accounts.proposal.has_passed(current_time), CustomError::ProposalNotPassed);
+    require!(
+        current_time >= ctx.accounts.proposal.end_time + governance_config.execution_delay,
+        CustomError::ProposalNotPassed
+    );
+
+    // Proposal must target exactly this symbol
+    match &ctx.accounts.proposal.proposal_type {
+        ProposalType::SymbolRelease { normalized_symbol } => {
+            require!(
+                *normalized_symbol == symbol_registry.normalized_symbol,
+                CustomError::ProposalMismatch
+            );
+        },
+        _ => return err!(CustomError::ProposalMismatch),
+    }
+
+    let normalized_symbol = symbol_registry.normalized_symbol.clone();
+    let mint = symbol_registry.mint;
+
+    // Mark proposal as executed; the registry account is closed on exit
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed = true;
+    proposal.executed_at = Some(current_time);
+    proposal.executed_by = Some(ctx.accounts.authority.key());
+    proposal.updated_at = current_time;
+
+    emit!(SymbolReleased {
+        normalized_symbol: normalized_symbol.clone(),
+        mint,
+        proposal_id: proposal.id,
+        timestamp: current_time,
+    });
+
+    msg!("Symbol {} released under proposal {}", normalized_symbol, proposal.id);
+
+    Ok(())
+}
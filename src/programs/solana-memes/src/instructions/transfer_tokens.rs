@@ -13,7 +13,11 @@ pub struct TransferTokens<'info> {
     
     pub authority: Signer<'info>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"anti_bot_config", from.mint.as_ref()],
+        bump = anti_bot_config.bump
+    )]
     pub anti_bot_config: Account<'info, AntiBotConfig>,
     
     pub token_program: Program<'info, Token>,
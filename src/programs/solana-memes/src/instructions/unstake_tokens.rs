@@ -0,0 +1,357 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::phase_1_3_structures::AdvancedStaking;
+use crate::errors::CustomError;
+use super::compounding_vault::compound_position;
+
+#[derive(Accounts)]
+pub struct UnstakeTokens<'info> {
+    #[account(
+        mut,
+        seeds = [b"staking_pool", staking_pool.mint.as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// The owner's own position; omitted when redeeming liquid receipt tokens
+    #[account(
+        mut,
+        seeds = [
+            b"staking_position",
+            staking_pool.key().as_ref(),
+            owner.key().as_ref(),
+            &[staking_position.tier as u8]
+        ],
+        bump = staking_position.bump,
+        has_one = owner @ CustomError::Unauthorized
+    )]
+    pub staking_position: Option<Account<'info, StakingPosition>>,
+
+    /// Compounding vault backing the receipt token, when redeeming receipts
+    #[account(
+        mut,
+        seeds = [b"compounding_vault", staking_pool.key().as_ref()],
+        bump = compounding_vault.bump
+    )]
+    pub compounding_vault: Option<Account<'info, CompoundingVault>>,
+
+    /// The vault's own position; checked against `compounding_vault` in the handler
+    #[account(mut)]
+    pub vault_position: Option<Account<'info, StakingPosition>>,
+
+    /// Receipt token mint; checked against `compounding_vault` in the handler
+    #[account(mut)]
+    pub receipt_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub receipt_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"staking_tiers"],
+        bump = staking_tier_config.bump
+    )]
+    pub staking_tier_config: Account<'info, StakingTierConfig>,
+
+    /// User's staking summary, kept in step with their tiered stakes when provided
+    #[account(
+        mut,
+        seeds = [b"advanced_staking", owner.key().as_ref()],
+        bump
+    )]
+    pub advanced_staking: Option<Account<'info, AdvancedStaking>>,
+
+    #[account(
+        mut,
+        seeds = [b"stake_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.stake_vault_bump
+    )]
+    pub stake_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"reward_vault", staking_pool.mint.as_ref()],
+        bump = staking_pool.reward_vault_bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = staking_pool.mint,
+        token::authority = owner
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct TokensUnstaked {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub early_withdrawal_fee: u64,
+    pub rewards_paid: u64,
+    pub position_closed: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ReceiptTokensRedeemed {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub receipt_amount: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+pub fn handler(ctx: Context<UnstakeTokens>, amount: u64) -> Result<()> {
+    if ctx.accounts.receipt_mint.is_some() {
+        return redeem_receipt(ctx, amount);
+    }
+
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let staking_position = ctx.accounts.staking_position
+        .as_mut()
+        .ok_or(CustomError::InvalidAccount)?;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(amount > 0, CustomError::InvalidAmount);
+    require!(amount <= staking_position.amount, CustomError::InsufficientStakeAmount);
+    require!(
+        current_time > staking_position.vote_locked_until,
+        CustomError::StakeCommittedToVote
+    );
+
+    // Settle rewards on the full stake before it shrinks
+    staking_pool.update_rewards(current_time).ok_or(CustomError::MathOverflow)?;
+    staking_position.settle_rewards(staking_pool).ok_or(CustomError::MathOverflow)?;
+
+    if current_time >= staking_position.end_time {
+        staking_position.is_locked = false;
+    }
+
+    // Exiting before the lock ends costs the early-withdrawal fee
+    let fee = if staking_position.is_locked {
+        (amount as u128 * staking_pool.early_withdrawal_fee as u128 / 100) as u64
+    } else {
+        0
+    };
+    let withdraw_amount = amount - fee;
+    let rewards_paid = staking_position.pending_rewards;
+
+    let tier = staking_position.tier;
+    let remaining = staking_position.amount - amount;
+    let multiplier_bps = ctx.accounts.staking_tier_config.multiplier_for(tier);
+
+    // The fee goes to the other stakers: take this position's weight out of the pool
+    // while distributing it, then re-add what remains from the post-fee index
+    if fee > 0 {
+        staking_position
+            .set_amount(staking_pool, 0, multiplier_bps)
+            .ok_or(CustomError::MathOverflow)?;
+        staking_pool.distribute_now(fee).ok_or(CustomError::MathOverflow)?;
+        staking_pool.total_rewards = staking_pool.total_rewards
+            .checked_add(fee)
+            .ok_or(CustomError::MathOverflow)?;
+        staking_position.reward_per_token_paid = staking_pool.reward_per_token_stored;
+    }
+    staking_position
+        .set_amount(staking_pool, remaining, multiplier_bps)
+        .ok_or(CustomError::MathOverflow)?;
+    staking_position.pending_rewards = 0;
+    staking_position.rewards_claimed = staking_position.rewards_claimed
+        .checked_add(rewards_paid)
+        .ok_or(CustomError::MathOverflow)?;
+
+    let mint_key = staking_pool.mint;
+    let seeds = &[b"staking_pool".as_ref(), mint_key.as_ref(), &[staking_pool.bump]];
+    let signer = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.staking_pool.to_account_info(),
+            },
+            signer,
+        ),
+        withdraw_amount,
+    )?;
+    if fee > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_vault.to_account_info(),
+                    to: ctx.accounts.reward_vault.to_account_info(),
+                    authority: ctx.accounts.staking_pool.to_account_info(),
+                },
+                signer,
+            ),
+            fee,
+        )?;
+    }
+    if rewards_paid > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.staking_pool.to_account_info(),
+                },
+                signer,
+            ),
+            rewards_paid,
+        )?;
+    }
+
+    if let Some(advanced_staking) = &mut ctx.accounts.advanced_staking {
+        if tier == StakeTier::Flexible {
+            advanced_staking.total_flexible_staked = advanced_staking.total_flexible_staked.saturating_sub(amount);
+        } else {
+            advanced_staking.total_locked_staked = advanced_staking.total_locked_staked.saturating_sub(amount);
+        }
+        advanced_staking.updated_at = current_time;
+    }
+
+    // Refund rent once nothing is left staked or owed in fee revenue
+    let staking_position = ctx.accounts.staking_position
+        .as_ref()
+        .ok_or(CustomError::InvalidAccount)?;
+    let position_closed = staking_position.amount == 0 && staking_position.pending_fee_revenue == 0;
+    if position_closed {
+        staking_position.close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    emit!(TokensUnstaked {
+        pool: ctx.accounts.staking_pool.key(),
+        owner: ctx.accounts.owner.key(),
+        amount,
+        early_withdrawal_fee: fee,
+        rewards_paid,
+        position_closed,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Unstaked {} tokens ({} early-withdrawal fee), paid {} rewards",
+        amount,
+        fee,
+        rewards_paid
+    );
+
+    Ok(())
+}
+
+/// Burn liquid receipt tokens for their share of the compounding vault, rewards included
+fn redeem_receipt(mut ctx: Context<UnstakeTokens>, receipt_amount: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    require!(receipt_amount > 0, CustomError::InvalidAmount);
+
+    let accounts = &mut ctx.accounts;
+    let compounding_vault = accounts.compounding_vault
+        .as_mut()
+        .ok_or(CustomError::InvalidAccount)?;
+    let vault_position = accounts.vault_position
+        .as_mut()
+        .ok_or(CustomError::InvalidAccount)?;
+    let receipt_mint = accounts.receipt_mint
+        .as_ref()
+        .ok_or(CustomError::InvalidAccount)?;
+    let receipt_token_account = accounts.receipt_token_account
+        .as_ref()
+        .ok_or(CustomError::InvalidAccount)?;
+
+    // The optional accounts must belong to this pool's vault and the owner
+    require!(
+        vault_position.owner == compounding_vault.key()
+            && vault_position.pool == accounts.staking_pool.key()
+            && vault_position.tier == StakeTier::Flexible,
+        CustomError::InvalidAccount
+    );
+    require!(
+        compounding_vault.receipt_mint == Some(receipt_mint.key()),
+        CustomError::LiquidStakingNotEnabled
+    );
+    require!(
+        receipt_token_account.mint == receipt_mint.key()
+            && receipt_token_account.owner == accounts.owner.key(),
+        CustomError::InvalidAccount
+    );
+
+    require!(
+        receipt_amount <= compounding_vault.total_shares,
+        CustomError::InsufficientVaultShares
+    );
+
+    // Restake accrued rewards so the receipt redeems at its full value
+    let multiplier_bps = accounts.staking_tier_config.multiplier_for(StakeTier::Flexible);
+    compound_position(
+        &mut accounts.staking_pool,
+        vault_position,
+        &accounts.reward_vault,
+        &accounts.stake_vault,
+        &accounts.token_program,
+        multiplier_bps,
+        current_time,
+        0,
+    )?;
+
+    let assets = vault_position.amount;
+    let amount = compounding_vault
+        .assets_for_shares(receipt_amount, assets)
+        .ok_or(CustomError::MathOverflow)?;
+    require!(amount > 0, CustomError::InvalidAmount);
+
+    token::burn(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            Burn {
+                mint: receipt_mint.to_account_info(),
+                from: receipt_token_account.to_account_info(),
+                authority: accounts.owner.to_account_info(),
+            },
+        ),
+        receipt_amount,
+    )?;
+
+    vault_position
+        .set_amount(&mut accounts.staking_pool, assets - amount, multiplier_bps)
+        .ok_or(CustomError::MathOverflow)?;
+    compounding_vault.total_shares -= receipt_amount;
+
+    let mint_key = accounts.staking_pool.mint;
+    let seeds = &[b"staking_pool".as_ref(), mint_key.as_ref(), &[accounts.staking_pool.bump]];
+    let signer = &[&seeds[..]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            Transfer {
+                from: accounts.stake_vault.to_account_info(),
+                to: accounts.user_token_account.to_account_info(),
+                authority: accounts.staking_pool.to_account_info(),
+            },
+            signer,
+        ),
+        amount,
+    )?;
+
+    emit!(ReceiptTokensRedeemed {
+        pool: accounts.staking_pool.key(),
+        owner: accounts.owner.key(),
+        receipt_amount,
+        amount,
+        timestamp: current_time,
+    });
+
+    msg!("Redeemed {} receipt tokens for {} tokens", receipt_amount, amount);
+
+    Ok(())
+}
@@ -2,10 +2,64 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token};
 use crate::state::*;
 use crate::errors::CustomError;
+use crate::program::SolanaMemes;
 
 #[derive(Accounts)]
-pub struct UpdateTradingFee<'info> {
+pub struct InitializePlatformConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PlatformConfig::LEN,
+        seeds = [b"platform_config"],
+        bump
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, SolanaMemes>,
+
+    /// Only the program's upgrade authority can create the platform config
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ CustomError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
     #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_platform_config(ctx: Context<InitializePlatformConfig>) -> Result<()> {
+    let platform_config = &mut ctx.accounts.platform_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    platform_config.authority = ctx.accounts.authority.key();
+    platform_config.trading_fee_percentage = INITIAL_TRADING_FEE;
+    platform_config.min_trading_fee = MIN_TRADING_FEE;
+    platform_config.max_trading_fee = MAX_TRADING_FEE;
+    platform_config.fee_change_cooldown = FEE_CHANGE_COOLDOWN;
+    platform_config.last_fee_change = 0;
+    platform_config.pending_fee_change = None;
+    platform_config.emergency_pause = false;
+    platform_config.governance_quorum = PROPOSAL_QUORUM_PERCENTAGE;
+    platform_config.created_at = current_time;
+    platform_config.updated_at = current_time;
+    platform_config.bump = *ctx.bumps.get("platform_config").unwrap();
+
+    msg!("Platform config initialized with authority {}", platform_config.authority);
+    msg!("Trading fee: {}%", platform_config.trading_fee_percentage as f64 / 10.0);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTradingFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
     pub platform_config: Account<'info, PlatformConfig>,
     
     #[account(mut)]
@@ -50,7 +104,7 @@ pub fn handler(ctx: Context<UpdateTradingFee>, new_fee: u8, reason: String) -> R
     );
     
     // Validate reason length
-    require!(reason.len() <= 500, CustomError::InvalidFeeProposal);
+    require!(reason.len() <= PendingFeeChange::MAX_REASON_LEN, CustomError::InvalidFeeProposal);
     
     // Create pending fee change
     let implementation_time = current_time + FEE_IMPLEMENTATION_DELAY;
@@ -100,7 +154,11 @@ pub fn handler(ctx: Context<UpdateTradingFee>, new_fee: u8, reason: String) -> R
 
 #[derive(Accounts)]
 pub struct ExecuteFeeChange<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
     pub platform_config: Account<'info, PlatformConfig>,
     
     #[account(mut)]
@@ -161,7 +219,11 @@ pub fn execute_fee_change(ctx: Context<ExecuteFeeChange>) -> Result<()> {
 
 #[derive(Accounts)]
 pub struct CancelFeeChange<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"platform_config"],
+        bump = platform_config.bump
+    )]
     pub platform_config: Account<'info, PlatformConfig>,
     
     pub authority: Signer<'info>,
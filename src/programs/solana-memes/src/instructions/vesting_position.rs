@@ -0,0 +1,432 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, FreezeAccount, Mint, MintTo, SetAuthority, ThawAccount, Token, TokenAccount, Transfer};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use anchor_spl::associated_token::AssociatedToken;
+use mpl_token_metadata::instructions::CreateMetadataAccountV3CpiBuilder;
+use mpl_token_metadata::types::DataV2;
+use crate::state::*;
+use crate::errors::CustomError;
+
+#[derive(Accounts)]
+pub struct InitializeVestingTransferPolicy<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VestingTransferPolicy::LEN,
+        seeds = [b"vesting_transfer_policy"],
+        bump
+    )]
+    pub vesting_transfer_policy: Account<'info, VestingTransferPolicy>,
+
+    #[account(
+        seeds = [b"platform_config"],
+        bump = platform_config.bump,
+        has_one = authority @ CustomError::Unauthorized
+    )]
+    pub platform_config: Account<'info, PlatformConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateVestingTransferPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [b"vesting_transfer_policy"],
+        bump = vesting_transfer_policy.bump
+    )]
+    pub vesting_transfer_policy: Account<'info, VestingTransferPolicy>,
+
+    #[account(
+        mut,
+        seeds = [b"governance_proposal", proposal.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        seeds = [b"governance_config"],
+        bump
+    )]
+    pub governance_config: Account<'info, GovernanceConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TokenizeVesting<'info> {
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting.mint.as_ref(), vesting.beneficiary.as_ref()],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = vesting,
+        mint::freeze_authority = vesting
+    )]
+    pub position_mint: Account<'info, Mint>,
+
+    /// Position NFTs stay frozen so every transfer goes through the program
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = position_mint,
+        associated_token::authority = owner
+    )]
+    pub owner_position_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Metaplex metadata PDA, initialized by the Token Metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), position_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub metaplex_metadata: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: Metaplex Token Metadata program
+    #[account(address = mpl_token_metadata::ID @ CustomError::InvalidMetadataProgram)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct TransferVestingPosition<'info> {
+    #[account(
+        mut,
+        seeds = [b"vesting", vesting.mint.as_ref(), vesting.beneficiary.as_ref()],
+        bump = vesting.bump
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(
+        seeds = [b"vesting_transfer_policy"],
+        bump = vesting_transfer_policy.bump
+    )]
+    pub vesting_transfer_policy: Account<'info, VestingTransferPolicy>,
+
+    #[account(
+        constraint = vesting.position_mint == Some(position_mint.key()) @ CustomError::VestingNotTokenized
+    )]
+    pub position_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = position_mint,
+        associated_token::authority = holder
+    )]
+    pub holder_position_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Wallet receiving the position
+    pub recipient: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        associated_token::mint = position_mint,
+        associated_token::authority = recipient
+    )]
+    pub recipient_position_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct VestingTokenized {
+    pub vesting: Pubkey,
+    pub position_mint: Pubkey,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingPositionTransferred {
+    pub vesting: Pubkey,
+    pub position_mint: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VestingTransferPolicyUpdated {
+    pub min_holding_period: i64,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+/// Checks that `holder` controls the vesting: the recorded owner, who must also hold the
+/// frozen position NFT once tokenized. Position NFTs only move through `transfer_vesting_position`.
+pub fn authorize_holder(
+    vesting: &Vesting,
+    holder: Pubkey,
+    position_account: Option<&TokenAccount>,
+) -> Result<()> {
+    match vesting.position_mint {
+        Some(position_mint) => {
+            let position_account = position_account.ok_or(CustomError::Unauthorized)?;
+            require!(
+                vesting.owner == holder
+                    && position_account.mint == position_mint
+                    && position_account.owner == holder
+                    && position_account.amount == 1
+                    && position_account.is_frozen(),
+                CustomError::Unauthorized
+            );
+        },
+        None => require!(vesting.owner == holder, CustomError::Unauthorized),
+    }
+
+    Ok(())
+}
+
+pub fn initialize_vesting_transfer_policy(
+    ctx: Context<InitializeVestingTransferPolicy>,
+    min_holding_period: i64,
+) -> Result<()> {
+    require!(min_holding_period >= 0, CustomError::InvalidVestingTransferPolicy);
+
+    let vesting_transfer_policy = &mut ctx.accounts.vesting_transfer_policy;
+    vesting_transfer_policy.min_holding_period = min_holding_period;
+    vesting_transfer_policy.updated_at = Clock::get()?.unix_timestamp;
+    vesting_transfer_policy.bump = *ctx.bumps.get("vesting_transfer_policy").unwrap();
+
+    msg!("Vesting transfer policy initialized: {}s minimum holding period", min_holding_period);
+
+    Ok(())
+}
+
+pub fn update_vesting_transfer_policy(ctx: Context<UpdateVestingTransferPolicy>) -> Result<()> {
+    let governance_config = &ctx.accounts.governance_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    // Proposal must have passed and cleared the execution delay
+    require!(governance_config.is_active, CustomError::ProposalNotActive);
+    require!(!ctx.accounts.proposal.executed, CustomError::ProposalAlreadyExecuted);
+    require!(ctx.accounts.proposal.has_passed(current_time), CustomError::ProposalNotPassed);
+    require!(
+        current_time >= ctx.accounts.proposal.end_time + governance_config.execution_delay,
+        CustomError::ProposalNotPassed
+    );
+
+    let min_holding_period = match &ctx.accounts.proposal.proposal_type {
+        ProposalType::VestingTransferPolicyUpdate { min_holding_period } => *min_holding_period,
+        _ => return err!(CustomError::ProposalMismatch),
+    };
+    require!(min_holding_period >= 0, CustomError::InvalidVestingTransferPolicy);
+
+    let vesting_transfer_policy = &mut ctx.accounts.vesting_transfer_policy;
+    vesting_transfer_policy.min_holding_period = min_holding_period;
+    vesting_transfer_policy.updated_at = current_time;
+
+    // Mark proposal as executed
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed = true;
+    proposal.executed_at = Some(current_time);
+    proposal.executed_by = Some(ctx.accounts.authority.key());
+    proposal.updated_at = current_time;
+
+    emit!(VestingTransferPolicyUpdated {
+        min_holding_period,
+        proposal_id: proposal.id,
+        timestamp: current_time,
+    });
+
+    msg!("Vesting transfer policy updated under proposal {}", proposal.id);
+
+    Ok(())
+}
+
+pub fn tokenize_vesting(
+    ctx: Context<TokenizeVesting>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let vesting = &ctx.accounts.vesting;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    require!(vesting.owner == ctx.accounts.owner.key(), CustomError::Unauthorized);
+    require!(!vesting.revoked, CustomError::VestingRevoked);
+    require!(vesting.position_mint.is_none(), CustomError::VestingAlreadyTokenized);
+
+    require!(name.len() <= mpl_token_metadata::MAX_NAME_LENGTH, CustomError::InvalidMetadataName);
+    require!(symbol.len() <= mpl_token_metadata::MAX_SYMBOL_LENGTH, CustomError::InvalidMetadataSymbol);
+    require!(uri.len() <= mpl_token_metadata::MAX_URI_LENGTH, CustomError::InvalidMetadataUri);
+
+    let mint_key = vesting.mint;
+    let beneficiary_key = vesting.beneficiary;
+    let seeds = &[b"vesting".as_ref(), mint_key.as_ref(), beneficiary_key.as_ref(), &[vesting.bump]];
+    let signer = &[&seeds[..]];
+
+    // Create the Metaplex metadata so the position shows up as an NFT in wallets
+    CreateMetadataAccountV3CpiBuilder::new(&ctx.accounts.token_metadata_program.to_account_info())
+        .metadata(&ctx.accounts.metaplex_metadata.to_account_info())
+        .mint(&ctx.accounts.position_mint.to_account_info())
+        .mint_authority(&ctx.accounts.vesting.to_account_info())
+        .payer(&ctx.accounts.owner.to_account_info())
+        .update_authority(&ctx.accounts.vesting.to_account_info(), true)
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .rent(Some(&ctx.accounts.rent.to_account_info()))
+        .data(DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(false)
+        .invoke_signed(signer)?;
+
+    // Mint exactly one position NFT, then close the mint
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.position_mint.to_account_info(),
+                to: ctx.accounts.owner_position_account.to_account_info(),
+                authority: ctx.accounts.vesting.to_account_info(),
+            },
+            signer,
+        ),
+        1,
+    )?;
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.vesting.to_account_info(),
+                account_or_mint: ctx.accounts.position_mint.to_account_info(),
+            },
+            signer,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+
+    // Freeze so transfers go through the program and keep the owner in sync
+    token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.owner_position_account.to_account_info(),
+            mint: ctx.accounts.position_mint.to_account_info(),
+            authority: ctx.accounts.vesting.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.position_mint = Some(ctx.accounts.position_mint.key());
+    vesting.last_transfer_time = current_time;
+
+    emit!(VestingTokenized {
+        vesting: vesting.key(),
+        position_mint: ctx.accounts.position_mint.key(),
+        owner: vesting.owner,
+        timestamp: current_time,
+    });
+
+    msg!("Vesting {} tokenized as {}", vesting.key(), ctx.accounts.position_mint.key());
+
+    Ok(())
+}
+
+pub fn transfer_vesting_position(ctx: Context<TransferVestingPosition>) -> Result<()> {
+    let holder = ctx.accounts.holder.key();
+    let recipient = ctx.accounts.recipient.key();
+    let current_time = Clock::get()?.unix_timestamp;
+
+    authorize_holder(&ctx.accounts.vesting, holder, Some(&*ctx.accounts.holder_position_account))?;
+    let vesting = &ctx.accounts.vesting;
+    require!(recipient != holder, CustomError::InvalidAccount);
+    require!(!vesting.revoked, CustomError::VestingRevoked);
+    require!(
+        current_time >= vesting.last_transfer_time + ctx.accounts.vesting_transfer_policy.min_holding_period,
+        CustomError::HoldingPeriodNotMet
+    );
+
+    let mint_key = vesting.mint;
+    let beneficiary_key = vesting.beneficiary;
+    let seeds = &[b"vesting".as_ref(), mint_key.as_ref(), beneficiary_key.as_ref(), &[vesting.bump]];
+    let signer = &[&seeds[..]];
+
+    // Thaw both sides, move the NFT, and re-freeze it in the recipient's account
+    let position_accounts = [
+        ctx.accounts.holder_position_account.to_account_info(),
+        ctx.accounts.recipient_position_account.to_account_info(),
+    ];
+    let frozen = [
+        ctx.accounts.holder_position_account.is_frozen(),
+        ctx.accounts.recipient_position_account.is_frozen(),
+    ];
+    for (account, is_frozen) in position_accounts.iter().zip(frozen) {
+        if is_frozen {
+            token::thaw_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                ThawAccount {
+                    account: account.clone(),
+                    mint: ctx.accounts.position_mint.to_account_info(),
+                    authority: ctx.accounts.vesting.to_account_info(),
+                },
+                signer,
+            ))?;
+        }
+    }
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.holder_position_account.to_account_info(),
+                to: ctx.accounts.recipient_position_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        ),
+        1,
+    )?;
+    token::freeze_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        FreezeAccount {
+            account: ctx.accounts.recipient_position_account.to_account_info(),
+            mint: ctx.accounts.position_mint.to_account_info(),
+            authority: ctx.accounts.vesting.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    let vesting = &mut ctx.accounts.vesting;
+    vesting.owner = recipient;
+    vesting.last_transfer_time = current_time;
+
+    emit!(VestingPositionTransferred {
+        vesting: vesting.key(),
+        position_mint: ctx.accounts.position_mint.key(),
+        from: holder,
+        to: recipient,
+        timestamp: current_time,
+    });
+
+    msg!("Vesting position {} transferred from {} to {}", vesting.key(), holder, recipient);
+
+    Ok(())
+}
@@ -33,7 +33,65 @@ pub mod solana_memes {
         instructions::register_creator::handler(ctx, stake_amount)
     }
 
-    /// Create a new memecoin with vesting and anti-rug protection
+    /// Initialize how slashed creator stakes are split
+    pub fn initialize_slashing_config(
+        ctx: Context<InitializeSlashingConfig>,
+        compensation_share_bps: u16,
+    ) -> Result<()> {
+        instructions::creator_stake::initialize_slashing_config(ctx, compensation_share_bps)
+    }
+
+    /// Change the holders' compensation share of slashed stakes
+    pub fn update_slashing_config(
+        ctx: Context<UpdateSlashingConfig>,
+        compensation_share_bps: u16,
+    ) -> Result<()> {
+        instructions::creator_stake::update_slashing_config(ctx, compensation_share_bps)
+    }
+
+    /// Slash a rugging creator's escrowed stake and ban them
+    pub fn slash_creator_stake(
+        ctx: Context<SlashCreatorStake>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::creator_stake::slash_creator_stake(ctx, amount, reason)
+    }
+
+    /// Post the holder snapshot root for a compensation pool
+    pub fn post_compensation_root(
+        ctx: Context<PostCompensationRoot>,
+        merkle_root: [u8; 32],
+        snapshot_slot: u64,
+    ) -> Result<()> {
+        instructions::creator_stake::post_compensation_root(ctx, merkle_root, snapshot_slot)
+    }
+
+    /// Claim a holder's share of slashed creator stake with a merkle proof
+    pub fn claim_compensation(
+        ctx: Context<ClaimCompensation>,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::creator_stake::claim_compensation(ctx, amount, proof)
+    }
+
+    /// Send unclaimed compensation to the treasury after the claim period
+    pub fn sweep_compensation(ctx: Context<SweepCompensation>) -> Result<()> {
+        instructions::creator_stake::sweep_compensation(ctx)
+    }
+
+    /// Start the cooldown before a creator can withdraw their stake
+    pub fn request_deregistration(ctx: Context<RequestDeregistration>) -> Result<()> {
+        instructions::creator_stake::request_deregistration(ctx)
+    }
+
+    /// Return the creator stake after the cooldown and mark the profile deregistered
+    pub fn withdraw_creator_stake(ctx: Context<WithdrawCreatorStake>) -> Result<()> {
+        instructions::creator_stake::withdraw_creator_stake(ctx)
+    }
+
+    /// Create a new memecoin with vesting and anti-rug protection, and open its bonding curve
     pub fn create_token(
         ctx: Context<CreateToken>,
         name: String,
@@ -42,7 +100,13 @@ pub mod solana_memes {
         decimals: u8,
         total_supply: u64,
         creator_percent: u8,
-        vesting_seconds: i64,
+        beneficiaries: Vec<VestingBeneficiary>,
+        immutable_metadata: bool,
+        fixed_supply: Option<bool>,
+        normalized_symbol: String,
+        virtual_sol_reserves: u64,
+        virtual_token_reserves: u64,
+        lp_lock_seconds: i64,
     ) -> Result<()> {
         instructions::create_token::handler(
             ctx,
@@ -52,7 +116,13 @@ pub mod solana_memes {
             decimals,
             total_supply,
             creator_percent,
-            vesting_seconds,
+            beneficiaries,
+            immutable_metadata,
+            fixed_supply,
+            normalized_symbol,
+            virtual_sol_reserves,
+            virtual_token_reserves,
+            lp_lock_seconds,
         )
     }
 
@@ -64,15 +134,16 @@ pub mod solana_memes {
         instructions::transfer_tokens::handler(ctx, amount)
     }
 
-    /// Stake tokens to earn rewards
+    /// Stake tokens to earn rewards in a lock tier
     pub fn stake_tokens(
         ctx: Context<StakeTokens>,
         amount: u64,
+        tier: StakeTier,
     ) -> Result<()> {
-        instructions::stake_tokens::handler(ctx, amount)
+        instructions::stake_tokens::handler(ctx, amount, tier)
     }
 
-    /// Unstake tokens
+    /// Unstake tokens, or redeem liquid staking receipt tokens
     pub fn unstake_tokens(
         ctx: Context<UnstakeTokens>,
         amount: u64,
@@ -85,39 +156,180 @@ pub mod solana_memes {
         instructions::claim_rewards::handler(ctx)
     }
 
+    /// Create the stake and reward vaults for a token's staking pool
+    pub fn initialize_staking_vaults(ctx: Context<InitializeStakingVaults>) -> Result<()> {
+        instructions::staking_vaults::initialize_staking_vaults(ctx)
+    }
+
+    /// Initialize the stake tier reward multipliers
+    pub fn initialize_staking_tiers(ctx: Context<InitializeStakingTiers>) -> Result<()> {
+        instructions::staking_tiers::initialize_staking_tiers(ctx)
+    }
+
+    /// Apply a passed stake tier multiplier proposal
+    pub fn update_staking_tiers(ctx: Context<UpdateStakingTiers>) -> Result<()> {
+        instructions::staking_tiers::update_staking_tiers(ctx)
+    }
+
+    /// Add reward tokens to a staking pool
+    pub fn fund_rewards(ctx: Context<FundRewards>, amount: u64) -> Result<()> {
+        instructions::staking_vaults::fund_rewards(ctx, amount)
+    }
+
+    /// Create an auto-compounding vault for a staking pool
+    pub fn initialize_compounding_vault(
+        ctx: Context<InitializeCompoundingVault>,
+        frequency: phase_1_3_structures::AutoCompoundingFrequency,
+        custom_interval: Option<i64>,
+        crank_incentive_bps: u16,
+    ) -> Result<()> {
+        instructions::compounding_vault::initialize_compounding_vault(
+            ctx,
+            frequency,
+            custom_interval,
+            crank_incentive_bps,
+        )
+    }
+
+    /// Deposit tokens into a compounding vault for shares
+    pub fn deposit_to_vault(ctx: Context<DepositToVault>, amount: u64) -> Result<()> {
+        instructions::compounding_vault::deposit_to_vault(ctx, amount)
+    }
+
+    /// Redeem compounding vault shares for tokens
+    pub fn withdraw_from_vault(ctx: Context<WithdrawFromVault>, shares: u64) -> Result<()> {
+        instructions::compounding_vault::withdraw_from_vault(ctx, shares)
+    }
+
+    /// Restake a compounding vault's rewards (permissionless crank)
+    pub fn compound_vault(ctx: Context<CompoundVault>) -> Result<()> {
+        instructions::compounding_vault::compound_vault(ctx)
+    }
+
+    /// Enable a liquid staking receipt token for a pool's compounding vault
+    pub fn initialize_receipt_mint(
+        ctx: Context<InitializeReceiptMint>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::liquid_staking::initialize_receipt_mint(ctx, name, symbol, uri)
+    }
+
+    /// Stake tokens for liquid receipt tokens, redeemable through `unstake_tokens`
+    pub fn stake_liquid(ctx: Context<StakeLiquid>, amount: u64) -> Result<()> {
+        instructions::liquid_staking::stake_liquid(ctx, amount)
+    }
+
+    /// Create the vesting PDA for one beneficiary of a token's creator allocation
+    pub fn create_beneficiary_vesting(
+        ctx: Context<CreateBeneficiaryVesting>,
+        index: u8,
+    ) -> Result<()> {
+        instructions::create_beneficiary_vesting::handler(ctx, index)
+    }
+
     /// Claim vested tokens
     pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
         instructions::claim_vested::handler(ctx)
     }
 
+    /// Revoke a creator's vesting under a passed proposal or confirmed alert
+    pub fn revoke_vesting(
+        ctx: Context<RevokeVesting>,
+        destination: RevocationDestination,
+    ) -> Result<()> {
+        instructions::revoke_vesting::handler(ctx, destination)
+    }
+
+    /// Initialize the transfer policy for tokenized vesting positions
+    pub fn initialize_vesting_transfer_policy(
+        ctx: Context<InitializeVestingTransferPolicy>,
+        min_holding_period: i64,
+    ) -> Result<()> {
+        instructions::vesting_position::initialize_vesting_transfer_policy(ctx, min_holding_period)
+    }
+
+    /// Apply a passed vesting transfer policy proposal
+    pub fn update_vesting_transfer_policy(ctx: Context<UpdateVestingTransferPolicy>) -> Result<()> {
+        instructions::vesting_position::update_vesting_transfer_policy(ctx)
+    }
+
+    /// Represent a vesting account as a transferable NFT
+    pub fn tokenize_vesting(
+        ctx: Context<TokenizeVesting>,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        instructions::vesting_position::tokenize_vesting(ctx, name, symbol, uri)
+    }
+
+    /// Transfer a vesting position NFT and its claim rights
+    pub fn transfer_vesting_position(ctx: Context<TransferVestingPosition>) -> Result<()> {
+        instructions::vesting_position::transfer_vesting_position(ctx)
+    }
+
     /// Create a governance proposal
     pub fn create_proposal(
-        ctx: Context<CreateProposal>,
+        ctx: Context<CreateGovernanceProposal>,
         title: String,
         description: String,
         proposal_type: ProposalType,
     ) -> Result<()> {
-        instructions::create_proposal::handler(ctx, title, description, proposal_type)
+        instructions::governance_voting::create_proposal(ctx, title, description, proposal_type)
     }
 
     /// Vote on a proposal
     pub fn vote(
-        ctx: Context<Vote>,
-        proposal_id: u64,
+        ctx: Context<VoteOnProposal>,
         vote_type: VoteType,
     ) -> Result<()> {
-        instructions::vote::handler(ctx, proposal_id, vote_type)
+        instructions::governance_voting::vote(ctx, vote_type)
     }
 
     /// Execute a passed proposal
-    pub fn execute_proposal(
-        ctx: Context<ExecuteProposal>,
-        proposal_id: u64,
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::governance_voting::execute_proposal(ctx)
+    }
+
+    /// Deploy the platform governance token and its config PDA
+    pub fn deploy_governance_token(ctx: Context<DeployGovernanceToken>) -> Result<()> {
+        instructions::deploy_governance_token::handler(ctx)
+    }
+
+    /// Open platform governance once enough tokens are distributed
+    pub fn activate_governance(ctx: Context<ActivateGovernance>) -> Result<()> {
+        instructions::deploy_governance_token::activate_governance(ctx)
+    }
+
+    /// Configure the inflation cap and schedule for a non-fixed-supply token
+    pub fn initialize_inflation_schedule(
+        ctx: Context<InitializeInflationSchedule>,
+        max_supply: u64,
+        period_seconds: i64,
+        max_inflation_bps_per_period: u16,
     ) -> Result<()> {
-        instructions::execute_proposal::handler(ctx, proposal_id)
+        instructions::mint_tokens::initialize_inflation_schedule(
+            ctx,
+            max_supply,
+            period_seconds,
+            max_inflation_bps_per_period,
+        )
+    }
+
+    /// Open a vote among the token's stakers on minting new supply
+    pub fn propose_mint(ctx: Context<ProposeMint>, amount: u64) -> Result<()> {
+        instructions::mint_tokens::propose_mint(ctx, amount)
+    }
+
+    /// Vote on a mint proposal with a staking position in that token
+    pub fn vote_mint_proposal(ctx: Context<VoteMintProposal>, approve: bool) -> Result<()> {
+        instructions::mint_tokens::vote_mint_proposal(ctx, approve)
     }
 
-    /// Mint additional tokens (only for token creator with restrictions)
+    /// Mint additional tokens through the program mint authority after a passed mint proposal;
+    /// permissionless, since the proposal fixes the amount and recipient
     pub fn mint_tokens(
         ctx: Context<MintTokens>,
         amount: u64,
@@ -195,14 +407,62 @@ pub mod solana_memes {
         buyback::transfer_authority(ctx, new_authority)
     }
 
+    /// Buy tokens from the bonding curve with SOL
+    pub fn buy(
+        ctx: Context<BuyFromCurve>,
+        sol_amount: u64,
+        min_tokens_out: u64,
+    ) -> Result<()> {
+        instructions::bonding_curve::buy(ctx, sol_amount, min_tokens_out)
+    }
+
+    /// Sell tokens back to the bonding curve for SOL
+    pub fn sell(
+        ctx: Context<SellToCurve>,
+        token_amount: u64,
+        min_sol_out: u64,
+    ) -> Result<()> {
+        instructions::bonding_curve::sell(ctx, token_amount, min_sol_out)
+    }
+
+    /// Move a completed bonding curve into the token's liquidity pool (permissionless)
+    pub fn graduate(ctx: Context<Graduate>) -> Result<()> {
+        instructions::graduate::handler(ctx)
+    }
+
+    /// Burn the LP tokens held since graduation once their burn delay has passed
+    pub fn burn_locked_liquidity(ctx: Context<BurnLockedLiquidity>) -> Result<()> {
+        instructions::graduate::burn_locked_liquidity(ctx)
+    }
+
+    /// Reserve a ticker for a verified creator
+    pub fn reserve_symbol(
+        ctx: Context<ReserveSymbol>,
+        normalized_symbol: String,
+    ) -> Result<()> {
+        instructions::symbol_registry::reserve_symbol(ctx, normalized_symbol)
+    }
+
+    /// Release a squatted ticker after a passed governance proposal
+    pub fn release_symbol(ctx: Context<ReleaseSymbol>) -> Result<()> {
+        instructions::symbol_registry::release_symbol(ctx)
+    }
+
     /// Create launch pass NFT (required for token creation)
     pub fn create_launch_pass(
         ctx: Context<CreateLaunchPass>,
         name: String,
         symbol: String,
         uri: String,
+        expires_at: Option<i64>,
+        transferable: bool,
     ) -> Result<()> {
-        instructions::create_launch_pass::handler(ctx, name, symbol, uri)
+        instructions::create_launch_pass::handler(ctx, name, symbol, uri, expires_at, transferable)
+    }
+
+    /// Bind a held launch pass to the creator profile for the next launch
+    pub fn bind_launch_pass(ctx: Context<BindLaunchPass>) -> Result<()> {
+        instructions::create_launch_pass::bind_launch_pass(ctx)
     }
 
     /// Add liquidity to DEX
@@ -249,6 +509,29 @@ pub mod solana_memes {
         instructions::choose_vesting_option::handler(ctx, option)
     }
 
+    /// Post the holder snapshot root for a `Distribute` vesting choice
+    pub fn post_distribution_root(
+        ctx: Context<PostDistributionRoot>,
+        merkle_root: [u8; 32],
+        snapshot_slot: u64,
+    ) -> Result<()> {
+        instructions::holder_distribution::post_distribution_root(ctx, merkle_root, snapshot_slot)
+    }
+
+    /// Claim a holder's share of a distribution
+    pub fn claim_distribution(
+        ctx: Context<ClaimDistribution>,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::holder_distribution::claim_distribution(ctx, amount, proof)
+    }
+
+    /// Sweep unclaimed distribution tokens to the treasury after the deadline
+    pub fn sweep_distribution(ctx: Context<SweepDistribution>) -> Result<()> {
+        instructions::holder_distribution::sweep_distribution(ctx)
+    }
+
     /// Collect trading fee
     pub fn collect_trading_fee(
         ctx: Context<CollectTradingFee>,
@@ -256,12 +539,33 @@ pub mod solana_memes {
     ) -> Result<()> {
         instructions::collect_trading_fee::handler(ctx, trade_amount)
     }
-    
-    /// Validate creator limits
-    pub fn validate_creator_limits(
-        ctx: Context<ValidateCreatorLimits>,
+
+    /// Create the fee revenue pool that pays the staker share of trading fees in SOL
+    pub fn initialize_fee_revenue_pool(
+        ctx: Context<InitializeFeeRevenuePool>,
+        epoch_duration: i64,
     ) -> Result<()> {
-        instructions::validate_creator_limits::handler(ctx)
+        instructions::fee_revenue::initialize_fee_revenue_pool(ctx, epoch_duration)
+    }
+
+    /// Claim accrued fee revenue for a staking position
+    pub fn claim_fee_revenue(ctx: Context<ClaimFeeRevenue>) -> Result<()> {
+        instructions::fee_revenue::claim_fee_revenue(ctx)
+    }
+
+    /// Record the current fee revenue epoch and start the next one
+    pub fn close_fee_epoch(ctx: Context<CloseFeeEpoch>) -> Result<()> {
+        instructions::fee_revenue::close_fee_epoch(ctx)
+    }
+    
+    /// Initialize the creator policy with default reputation tiers
+    pub fn initialize_creator_policy(ctx: Context<InitializeCreatorPolicy>) -> Result<()> {
+        instructions::creator_policy::initialize_creator_policy(ctx)
+    }
+
+    /// Replace the creator policy tiers after a passed governance proposal
+    pub fn update_creator_policy(ctx: Context<UpdateCreatorPolicy>) -> Result<()> {
+        instructions::creator_policy::update_creator_policy(ctx)
     }
     
     /// Create liquidity bootstrapping pool
@@ -302,6 +606,11 @@ pub mod solana_memes {
         instructions::finalize_lbm::handler(ctx)
     }
 
+    /// Create the platform config PDA that holds the trading fee and treasury authority
+    pub fn initialize_platform_config(ctx: Context<InitializePlatformConfig>) -> Result<()> {
+        instructions::update_trading_fee::initialize_platform_config(ctx)
+    }
+
     /// Update trading fee (governance controlled)
     pub fn update_trading_fee(
         ctx: Context<UpdateTradingFee>,
@@ -398,52 +707,13 @@ pub mod solana_memes {
         )
     }
 
-    pub fn initialize_enhanced_token_holder(
-        ctx: Context<InitializeEnhancedTokenHolder>,
-        staked_amount: u64,
-        staking_duration: i64,
-        community_contribution: u64,
-        token_holding: u64,
-        consistency_score: u64,
-        reputation_score: i32,
-        participation_history: u64,
-        contribution_quality: u64,
-    ) -> Result<()> {
-        instructions::fair_voting_management::initialize_enhanced_token_holder(
-            ctx,
-            staked_amount,
-            staking_duration,
-            community_contribution,
-            token_holding,
-            consistency_score,
-            reputation_score,
-            participation_history,
-            contribution_quality,
-        )
+    pub fn initialize_enhanced_token_holder(ctx: Context<InitializeEnhancedTokenHolder>) -> Result<()> {
+        instructions::fair_voting_management::initialize_enhanced_token_holder(ctx)
     }
 
-    pub fn update_voting_power(
-        ctx: Context<UpdateVotingPower>,
-        new_staked_amount: u64,
-        new_staking_duration: i64,
-        new_community_contribution: u64,
-        new_token_holding: u64,
-        new_consistency_score: u64,
-        new_reputation_score: i32,
-        new_participation_history: u64,
-        new_contribution_quality: u64,
-    ) -> Result<()> {
-        instructions::fair_voting_management::update_voting_power(
-            ctx,
-            new_staked_amount,
-            new_staking_duration,
-            new_community_contribution,
-            new_token_holding,
-            new_consistency_score,
-            new_reputation_score,
-            new_participation_history,
-            new_contribution_quality,
-        )
+    /// Recompute a holder's voting power from their stake, balance, creator profile and votes cast
+    pub fn refresh_voting_power(ctx: Context<RefreshVotingPower>) -> Result<()> {
+        instructions::fair_voting_management::refresh_voting_power(ctx)
     }
 
     pub fn initialize_creator_performance(
@@ -562,6 +832,14 @@ pub mod solana_memes {
         )
     }
 
+    /// Resolve a suspicious activity alert (governance authority only)
+    pub fn resolve_suspicious_activity_alert(
+        ctx: Context<ResolveSuspiciousActivityAlert>,
+        resolution: AlertResolution,
+    ) -> Result<()> {
+        instructions::fair_voting_management::resolve_suspicious_activity_alert(ctx, resolution)
+    }
+
     pub fn initialize_penalty_system(
         ctx: Context<InitializePenaltySystem>,
         warning_penalty: u64,
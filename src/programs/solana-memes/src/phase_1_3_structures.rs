@@ -560,6 +560,19 @@ pub enum AutoCompoundingFrequency {
     Custom,
 }
 
+impl AutoCompoundingFrequency {
+    /// Seconds between compounds; `Custom` intervals are supplied separately
+    pub fn interval_seconds(&self) -> Option<i64> {
+        const DAY: i64 = 24 * 60 * 60;
+        match self {
+            AutoCompoundingFrequency::Daily => Some(DAY),
+            AutoCompoundingFrequency::Weekly => Some(7 * DAY),
+            AutoCompoundingFrequency::Monthly => Some(30 * DAY),
+            AutoCompoundingFrequency::Custom => None,
+        }
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum StakeType {
     Flexible,
@@ -3,7 +3,8 @@ use anchor_lang::prelude::*;
 /// Vesting schedule for creator tokens with distribution options
 #[account]
 pub struct Vesting {
-    pub owner: Pubkey,
+    pub owner: Pubkey,                  // Current holder; follows the position NFT once tokenized
+    pub beneficiary: Pubkey,            // Original wallet, fixed for PDA derivation
     pub mint: Pubkey,
     pub amount: u64,
     pub start_time: i64,
@@ -16,16 +17,198 @@ pub struct Vesting {
     pub distribution_choice: Option<VestingOption>,
     pub choice_deadline: i64,
     pub choice_made: bool,
+    pub bump: u8,
+    pub schedule: VestingSchedule,
+    pub position_mint: Option<Pubkey>,  // NFT representing this vesting, if tokenized
+    pub last_transfer_time: i64,        // Start of the current holder's holding period
+}
+
+impl Vesting {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + (1 + 8) + (1 + 1) + 8 + 1 + 1
+        + VestingSchedule::LEN + (1 + 32) + 8;
+
+    /// Total amount unlocked by the schedule at `current_time`; revocation stops further unlocking
+    pub fn vested_amount(&self, current_time: i64) -> u64 {
+        let current_time = self.revoke_time.map_or(current_time, |revoked_at| current_time.min(revoked_at));
+        if current_time < self.cliff_time {
+            return 0;
+        }
+        if current_time >= self.end_time {
+            return self.amount;
+        }
+        let elapsed = (current_time - self.start_time) as u128;
+        let duration = (self.end_time - self.start_time) as u128;
+        match self.schedule {
+            VestingSchedule::CliffLinear => {
+                (self.amount as u128 * elapsed / duration) as u64
+            },
+            VestingSchedule::Step { tranches } => {
+                let unlocked_tranches = elapsed * tranches as u128 / duration;
+                (self.amount as u128 * unlocked_tranches / tranches as u128) as u64
+            },
+        }
+    }
+}
+
+/// Where a revoked vesting's remaining tokens are sent
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RevocationDestination {
+    Burn,
+    StakingPool,
+    Treasury,
+}
+
+/// How a vesting allocation unlocks between start and end
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum VestingSchedule {
+    CliffLinear,                // Nothing before the cliff, then linear from start to end
+    Step { tranches: u8 },      // Equal tranches at fixed intervals, e.g. 4 for 25% per quarter
+}
+
+impl VestingSchedule {
+    pub const LEN: usize = 1 + 1;
+
+    /// Validate the schedule against the platform vesting bounds
+    pub fn is_valid(&self, cliff_seconds: i64, vesting_seconds: i64) -> bool {
+        if vesting_seconds < MIN_VESTING_PERIOD || vesting_seconds > MAX_VESTING_PERIOD {
+            return false;
+        }
+        match self {
+            VestingSchedule::CliffLinear => cliff_seconds >= 0 && cliff_seconds < vesting_seconds,
+            VestingSchedule::Step { tranches } => {
+                *tranches >= 2
+                    && *tranches <= MAX_VESTING_TRANCHES
+                    && cliff_seconds >= 0
+                    && cliff_seconds < vesting_seconds
+            },
+        }
+    }
+}
+
+pub const MAX_VESTING_TRANCHES: u8 = 12;
+pub const PERFORMANCE_RELEASE_DEADLINE: i64 = 180 * 24 * 60 * 60; // Held-back tokens unlock 180 days after vesting ends
+
+/// Governance-tunable rules for transferring tokenized vesting positions
+#[account]
+pub struct VestingTransferPolicy {
+    pub min_holding_period: i64,        // Seconds a holder must keep a position before transferring it
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl VestingTransferPolicy {
+    pub const LEN: usize = 8 + 8 + 1;
+}
+
+/// Creator allocation split across beneficiaries, each vesting through its own `Vesting` PDA
+#[account]
+pub struct CreatorAllocation {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub total_amount: u64,
+    pub created_at: i64,                        // Vesting start for every beneficiary
+    pub beneficiaries: Vec<BeneficiaryAllocation>,
+    pub bump: u8,
+}
+
+impl CreatorAllocation {
+    pub const MAX_BENEFICIARIES: usize = 5;
+
+    pub const LEN: usize = 32 + 32 + 8 + 8
+        + (4 + BeneficiaryAllocation::LEN * Self::MAX_BENEFICIARIES)
+        + 1;
+}
+
+/// One beneficiary's slice of the creator allocation
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BeneficiaryAllocation {
+    pub wallet: Pubkey,
+    pub share_bps: u16,             // Share of total supply; all shares sum to creator_percent
+    pub cliff_seconds: i64,
+    pub vesting_seconds: i64,
+    pub amount: u64,
+    pub vesting_created: bool,
+    pub schedule: VestingSchedule,
+}
+
+impl BeneficiaryAllocation {
+    pub const LEN: usize = 32 + 2 + 8 + 8 + 8 + 1 + VestingSchedule::LEN;
+}
+
+/// Beneficiary parameters supplied to `create_token`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VestingBeneficiary {
+    pub wallet: Pubkey,
+    pub share_bps: u16,
+    pub cliff_seconds: i64,
+    pub vesting_seconds: i64,
+    pub schedule: VestingSchedule,
 }
 
 /// Post-vesting distribution options
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
 pub enum VestingOption {
     Withdraw,      // Take all tokens
     Burn,          // Burn 50%, keep 50%
     Distribute,    // Distribute 50% to holders, keep 50%
 }
 
+pub const VESTING_CHOICE_WINDOW: i64 = 30 * 24 * 60 * 60; // Opens once held-back tokens unlock
+pub const DISTRIBUTION_CLAIM_PERIOD: i64 = 90 * 24 * 60 * 60;
+
+/// Holder distribution funded by a `VestingOption::Distribute` choice
+#[account]
+pub struct HolderDistribution {
+    pub vesting: Pubkey,
+    pub mint: Pubkey,
+    pub merkle_root: [u8; 32],          // Root over keccak(holder || amount) leaves of the snapshot
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub claim_count: u32,
+    pub snapshot_slot: u64,
+    pub created_at: i64,
+    pub claim_deadline: i64,            // Unclaimed tokens can be swept after this
+    pub swept: bool,
+    pub bump: u8,
+}
+
+impl HolderDistribution {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 8 + 4 + 8 + 8 + 8 + 1 + 1;
+
+    /// Verify a holder's leaf against the root
+    pub fn verify_claim(&self, holder: &Pubkey, amount: u64, proof: &[[u8; 32]]) -> bool {
+        verify_merkle_claim(&self.merkle_root, holder, amount, proof)
+    }
+}
+
+/// Verify a keccak(holder || amount) leaf against `root` using sorted-pair hashing
+pub fn verify_merkle_claim(root: &[u8; 32], holder: &Pubkey, amount: u64, proof: &[[u8; 32]]) -> bool {
+    use anchor_lang::solana_program::keccak::hashv;
+
+    let mut computed = hashv(&[holder.as_ref(), &amount.to_le_bytes()]).0;
+    for node in proof {
+        computed = if computed <= *node {
+            hashv(&[&computed, node]).0
+        } else {
+            hashv(&[node, &computed]).0
+        };
+    }
+    computed == *root
+}
+
+/// Receipt marking a holder's distribution share as claimed
+#[account]
+pub struct DistributionClaim {
+    pub distribution: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub claimed_at: i64,
+}
+
+impl DistributionClaim {
+    pub const LEN: usize = 32 + 32 + 8 + 8;
+}
+
         /// Enhanced creator profile with reputation-based allocation
         #[account]
         pub struct CreatorProfile {
@@ -40,14 +223,20 @@ pub enum VestingOption {
             pub total_volume: u64,
             pub is_banned: bool,
             pub ban_reason: String,
-            pub launch_pass_required: bool,
-            pub launch_pass_mint: Option<Pubkey>,
+            pub launch_pass_required: bool, // Always set: every launch consumes a bound pass
+            pub launch_pass_mint: Option<Pubkey>, // Pass bound for the next launch
             pub max_allocation_percent: u8,  // Based on reputation (max 7%)
             pub total_profit_shared: u64,    // Total profits shared with community
             pub weekly_creation_count: u8,   // Track weekly creations (max 2 per week)
             pub last_week_reset: i64,        // Track when weekly count resets
+            pub deregistration_requested_at: Option<i64>, // Start of the withdrawal cooldown
+            pub bump: u8,                    // One profile per creator, at [b"creator_profile", owner]
         }
 
+impl CreatorProfile {
+    pub const LEN: usize = 1 + 32 + 8 + 8 + 4 + 4 + 4 + 4 + 8 + 1 + (4 + MAX_BAN_REASON_LENGTH) + 1 + 33 + 1 + 8 + 1 + 8 + 9 + 1;
+}
+
 /// Token metadata and configuration
 #[account]
 pub struct TokenMetadata {
@@ -66,11 +255,172 @@ pub struct TokenMetadata {
     pub risk_score: u8,
     pub liquidity_locked: bool,
     pub liquidity_lock_amount: u64,
-    pub liquidity_lock_end: i64,
+    pub liquidity_lock_end: i64,            // For curve launches, when the held LP tokens can be burned
     pub anti_bot_enabled: bool,
     pub anti_bot_window: i64,
     pub max_transaction_size: u64,
     pub min_transaction_size: u64,
+    pub metaplex_metadata: Pubkey,   // Token Metadata program account for this mint
+    pub metadata_is_mutable: bool,
+    pub is_fixed_supply: bool,       // Mint and freeze authority revoked at launch
+    pub bump: u8,
+}
+
+impl TokenMetadata {
+    pub const MAX_NAME_LEN: usize = 200;
+    pub const MAX_SYMBOL_LEN: usize = 200;
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 32 + 32
+        + (4 + Self::MAX_NAME_LEN)
+        + (4 + Self::MAX_SYMBOL_LEN)
+        + (4 + Self::MAX_URI_LEN)
+        + 1 + 8 + 8 + 1 + 8 + 8 + 1 + 1 + 1 + 8 + 8 + 1 + 8 + 8 + 8
+        + 32 + 1 + 1 + 1;
+}
+
+/// Registry entry claiming a normalized ticker for a single token.
+/// Only tickers are registered: names are free-form display text, so they are
+/// deliberately left out rather than reserved on a first-come basis.
+#[account]
+pub struct SymbolRegistry {
+    pub normalized_symbol: String,
+    pub mint: Pubkey,               // Default until a token launches under this symbol
+    pub registered_by: Pubkey,      // Creator that launched or reserved the symbol
+    pub registered_at: i64,
+    pub reserved_until: i64,        // Reservation expiry for verified creators, 0 once launched
+    pub bump: u8,
+}
+
+impl SymbolRegistry {
+    pub const MAX_NORMALIZED_LEN: usize = 32;
+
+    pub const LEN: usize = (4 + Self::MAX_NORMALIZED_LEN) + 32 + 32 + 8 + 8 + 1;
+
+    /// Canonical form of a ticker: whitespace and separators dropped, uppercased,
+    /// full-width and common Cyrillic/Greek homoglyphs folded to ASCII, and
+    /// look-alike digits folded to letters (0 -> O, 1 -> I, 5 -> S)
+    pub fn normalize(symbol: &str) -> String {
+        symbol
+            .chars()
+            .filter_map(|c| {
+                let c = match c as u32 {
+                    // Full-width ASCII variants
+                    0xFF01..=0xFF5E => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+                    _ => c,
+                };
+                let c = match c {
+                    'А' | 'а' | 'Α' | 'α' => 'A',
+                    'В' | 'в' | 'Β' | 'β' => 'B',
+                    'С' | 'с' | 'Ϲ' => 'C',
+                    'Е' | 'е' | 'Ε' | 'ε' => 'E',
+                    'Н' | 'н' | 'Η' => 'H',
+                    'І' | 'і' | 'Ι' | 'ι' | '1' | '|' => 'I',
+                    'Ј' | 'ј' => 'J',
+                    'К' | 'к' | 'Κ' | 'κ' => 'K',
+                    'М' | 'м' | 'Μ' => 'M',
+                    'Ν' => 'N',
+                    'О' | 'о' | 'Ο' | 'ο' | '0' => 'O',
+                    'Р' | 'р' | 'Ρ' | 'ρ' => 'P',
+                    'Ѕ' | 'ѕ' | '5' | '$' => 'S',
+                    'Т' | 'т' | 'Τ' | 'τ' => 'T',
+                    'Х' | 'х' | 'Χ' | 'χ' => 'X',
+                    'У' | 'у' | 'Υ' | 'υ' => 'Y',
+                    'Ζ' => 'Z',
+                    _ => c,
+                };
+                let c = c.to_ascii_uppercase();
+                if c.is_ascii_alphanumeric() { Some(c) } else { None }
+            })
+            .collect()
+    }
+}
+
+/// Symbol reservations a creator made in the current reservation period
+#[account]
+pub struct SymbolReservationQuota {
+    pub creator: Pubkey,
+    pub period_start: i64,
+    pub reservations: u8,
+    pub bump: u8,
+}
+
+impl SymbolReservationQuota {
+    pub const LEN: usize = 32 + 8 + 1 + 1;
+}
+
+/// Symbol registry constants
+pub const SYMBOL_RESERVATION_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+pub const MAX_SYMBOL_RESERVATIONS_PER_PERIOD: u8 = 3;
+
+/// Governance-controlled inflation schedule for tokens launched without a fixed supply
+#[account]
+pub struct InflationSchedule {
+    pub mint: Pubkey,
+    pub max_supply: u64,                    // Hard cap on total supply
+    pub period_seconds: i64,                // Length of one inflation period
+    pub max_inflation_bps_per_period: u16,  // Max mint per period, in bps of supply at period start
+    pub period_start: i64,
+    pub period_start_supply: u64,
+    pub minted_in_period: u64,
+    pub total_minted: u64,
+    pub last_mint_time: i64,
+    pub bump: u8,
+    pub proposal_count: u64,                // Mint proposals opened; seeds the next proposal id
+}
+
+impl InflationSchedule {
+    pub const LEN: usize = 32 + 8 + 8 + 2 + 8 + 8 + 8 + 8 + 8 + 1 + 8;
+}
+
+/// Inflation schedule limits
+pub const MAX_INFLATION_BPS_PER_PERIOD: u16 = 500;        // 5% of supply per period
+pub const MIN_INFLATION_PERIOD: i64 = 30 * 24 * 60 * 60;  // 30 days
+pub const MINT_PROPOSAL_QUORUM_BPS: u16 = 1_000;          // 10% of the token's staked supply must vote
+
+/// Proposal by a token's own stakers to mint new supply of that token
+#[account]
+pub struct MintProposal {
+    pub mint: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,                  // Destination token account
+    pub yes_votes: u64,                     // Staked tokens voting for
+    pub no_votes: u64,                      // Staked tokens voting against
+    pub quorum_votes: u64,                  // Votes needed, fixed from the pool's stake at creation
+    pub start_time: i64,
+    pub end_time: i64,
+    pub executed: bool,
+    pub executed_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl MintProposal {
+    pub const LEN: usize = 32 + 8 + 32 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + (1 + 8) + 1;
+
+    /// Voting has closed, quorum was met and yes votes outnumber no votes
+    pub fn has_passed(&self, current_time: i64) -> bool {
+        current_time > self.end_time
+            && self.yes_votes.saturating_add(self.no_votes) >= self.quorum_votes
+            && self.yes_votes > self.no_votes
+    }
+}
+
+/// One staking position's vote on a mint proposal
+#[account]
+pub struct MintVote {
+    pub proposal: Pubkey,
+    pub position: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub approve: bool,
+    pub voted_at: i64,
+    pub bump: u8,
+}
+
+impl MintVote {
+    pub const LEN: usize = 32 + 32 + 32 + 8 + 1 + 8 + 1;
 }
 
 /// Staking rewards configuration
@@ -133,6 +483,90 @@ pub const MAX_CREATOR_ALLOCATION_PERCENT: u8 = 7;  // Maximum 7% allocation for
 pub const MAX_WEEKLY_CREATIONS: u8 = 2;            // Maximum 2 tokens per week
 pub const WEEK_IN_SECONDS: i64 = 604800;           // 7 days in seconds
 
+/// Platform-wide creator policy enforced by `create_token`, tunable by governance
+#[account]
+pub struct CreatorPolicy {
+    pub tiers: Vec<CreatorTier>,    // Sorted by increasing requirements
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl CreatorPolicy {
+    pub const MAX_TIERS: usize = 5;
+
+    pub const LEN: usize = (4 + CreatorTier::LEN * Self::MAX_TIERS) + 8 + 1;
+
+    /// Highest tier whose reputation and track-record thresholds the creator meets
+    pub fn tier_for(&self, reputation_score: i32, successful_tokens: u32) -> Option<&CreatorTier> {
+        self.tiers
+            .iter()
+            .filter(|tier| {
+                reputation_score >= tier.min_reputation_score
+                    && successful_tokens >= tier.min_successful_tokens
+            })
+            .last()
+    }
+
+    /// Tiers must be non-empty, bounded by the platform maximums and strictly increasing
+    pub fn validate_tiers(tiers: &[CreatorTier]) -> bool {
+        !tiers.is_empty()
+            && tiers.len() <= Self::MAX_TIERS
+            && tiers.iter().all(|tier| {
+                tier.max_allocation_percent > 0
+                    && tier.max_allocation_percent <= MAX_CREATOR_ALLOCATION_PERCENT
+                    && tier.max_weekly_creations > 0
+                    && tier.max_weekly_creations <= MAX_WEEKLY_CREATIONS
+                    && tier.creation_cooldown >= 0
+            })
+            && tiers.windows(2).all(|pair| {
+                pair[1].min_reputation_score >= pair[0].min_reputation_score
+                    && pair[1].min_successful_tokens >= pair[0].min_successful_tokens
+                    && (pair[1].min_reputation_score, pair[1].min_successful_tokens)
+                        != (pair[0].min_reputation_score, pair[0].min_successful_tokens)
+            })
+    }
+
+    pub fn default_tiers() -> Vec<CreatorTier> {
+        vec![
+            CreatorTier {
+                min_reputation_score: 0,
+                min_successful_tokens: 0,
+                max_allocation_percent: 3,
+                creation_cooldown: RATE_LIMIT_PERIOD,
+                max_weekly_creations: 1,
+            },
+            CreatorTier {
+                min_reputation_score: 100,
+                min_successful_tokens: 1,
+                max_allocation_percent: 5,
+                creation_cooldown: WEEK_IN_SECONDS,
+                max_weekly_creations: 1,
+            },
+            CreatorTier {
+                min_reputation_score: 500,
+                min_successful_tokens: 3,
+                max_allocation_percent: MAX_CREATOR_ALLOCATION_PERCENT,
+                creation_cooldown: 24 * 60 * 60,
+                max_weekly_creations: MAX_WEEKLY_CREATIONS,
+            },
+        ]
+    }
+}
+
+/// Reputation tier within the creator policy
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct CreatorTier {
+    pub min_reputation_score: i32,
+    pub min_successful_tokens: u32,
+    pub max_allocation_percent: u8,
+    pub creation_cooldown: i64,     // Minimum time between launches
+    pub max_weekly_creations: u8,
+}
+
+impl CreatorTier {
+    pub const LEN: usize = 4 + 4 + 1 + 8 + 1;
+}
+
 /// Fee distribution percentages (55/35/10 split)
 pub const STAKER_REWARD_PERCENTAGE: u8 = 55;       // 55% to stakers
 pub const DEVELOPMENT_PERCENTAGE: u8 = 35;         // 35% to development
@@ -152,6 +586,11 @@ pub struct PlatformConfig {
     pub governance_quorum: u8,         // Required quorum for fee changes
     pub created_at: i64,
     pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl PlatformConfig {
+    pub const LEN: usize = 32 + 1 + 1 + 1 + 8 + 8 + (1 + PendingFeeChange::LEN) + 1 + 1 + 8 + 8 + 1;
 }
 
 /// Pending fee change waiting for implementation
@@ -167,21 +606,215 @@ pub struct PendingFeeChange {
     pub total_votes: u64,
 }
 
+impl PendingFeeChange {
+    pub const MAX_REASON_LEN: usize = 500;
+
+    pub const LEN: usize = 1 + 32 + 8 + 8 + (4 + Self::MAX_REASON_LEN) + 8 + 8 + 8;
+}
+
 /// Staking pool for tokens
 #[account]
 pub struct StakingPool {
     pub mint: Pubkey,
     pub total_staked: u64,
-    pub total_rewards: u64,
-    pub reward_rate: u64,
+    pub total_rewards: u64,                 // Total ever funded into the reward vault
+    pub reward_rate: u64,                   // Daily reward per staked token, scaled by REWARD_RATE_DENOMINATOR
     pub last_update_time: i64,
     pub min_stake_amount: u64,
     pub max_stake_amount: u64,
     pub lock_period: i64,
     pub early_withdrawal_fee: u8,
     pub is_active: bool,
+    pub bump: u8,
+    pub reward_per_token_stored: u128,      // Accumulated reward per staked token, scaled by REWARD_PER_TOKEN_PRECISION
+    pub undistributed_rewards: u64,         // Funded rewards not yet accrued to stakers
+    pub reward_vault_bump: u8,
+    pub stake_vault_bump: u8,
+    pub total_weighted_staked: u64,         // Sum of tier-weighted stakes; rewards accrue against this
+    pub fee_revenue_per_token_stored: u128, // Accumulated platform fee revenue (lamports) per fee-earning weighted token
+    pub fee_weighted_staked: u64,           // Weighted stake earning fee revenue; excludes compounding vault positions
+    pub fee_revenue_pending: u64,           // Deposited fee revenue not yet released into the fee index
+    pub fee_revenue_release_end: i64,       // Pending fee revenue streams out linearly until this time
+}
+
+impl StakingPool {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1 + 16 + 8 + 1 + 1 + 8 + 16 + 8 + 8 + 8;
+
+    /// Accrue rewards and release fee revenue up to `current_time`, capped by what the reward vault holds
+    pub fn update_rewards(&mut self, current_time: i64) -> Option<()> {
+        self.release_fee_revenue(current_time)?;
+
+        let elapsed = current_time.saturating_sub(self.last_update_time).max(0) as u128;
+        self.last_update_time = current_time;
+        if elapsed == 0 || self.total_weighted_staked == 0 || self.undistributed_rewards == 0 {
+            return Some(());
+        }
+
+        let emitted = (self.total_weighted_staked as u128)
+            .checked_mul(self.reward_rate as u128)?
+            .checked_mul(elapsed)?
+            .checked_div(REWARD_RATE_DENOMINATOR.checked_mul(SECONDS_PER_DAY)?)?;
+        let emitted = std::cmp::min(emitted, self.undistributed_rewards as u128);
+
+        let increment = emitted
+            .checked_mul(REWARD_PER_TOKEN_PRECISION)?
+            .checked_div(self.total_weighted_staked as u128)?;
+        self.reward_per_token_stored = self.reward_per_token_stored.checked_add(increment)?;
+        self.undistributed_rewards = self.undistributed_rewards.checked_sub(emitted as u64)?;
+        Some(())
+    }
+
+    /// Release pending fee revenue linearly until `fee_revenue_release_end`, so a stake
+    /// opened just before a fee deposit earns only the share streamed while it was staked.
+    /// The stream pauses while no stake earns fee revenue.
+    fn release_fee_revenue(&mut self, current_time: i64) -> Option<()> {
+        let elapsed = current_time.saturating_sub(self.last_update_time).max(0);
+        if elapsed == 0 || self.fee_revenue_pending == 0 {
+            return Some(());
+        }
+        if self.fee_weighted_staked == 0 {
+            self.fee_revenue_release_end = self.fee_revenue_release_end.checked_add(elapsed)?;
+            return Some(());
+        }
+
+        let remaining = self.fee_revenue_release_end.saturating_sub(self.last_update_time);
+        let released = if elapsed >= remaining {
+            self.fee_revenue_pending
+        } else {
+            ((self.fee_revenue_pending as u128)
+                .checked_mul(elapsed as u128)?
+                .checked_div(remaining as u128)?) as u64
+        };
+
+        let increment = (released as u128)
+            .checked_mul(REWARD_PER_TOKEN_PRECISION)?
+            .checked_div(self.fee_weighted_staked as u128)?;
+        self.fee_revenue_per_token_stored = self.fee_revenue_per_token_stored.checked_add(increment)?;
+        self.fee_revenue_pending = self.fee_revenue_pending.checked_sub(released)?;
+        Some(())
+    }
+
+    /// Pay `amount` to current stakers immediately, or hold it for future stakers
+    pub fn distribute_now(&mut self, amount: u64) -> Option<()> {
+        if self.total_weighted_staked == 0 {
+            self.undistributed_rewards = self.undistributed_rewards.checked_add(amount)?;
+            return Some(());
+        }
+        let increment = (amount as u128)
+            .checked_mul(REWARD_PER_TOKEN_PRECISION)?
+            .checked_div(self.total_weighted_staked as u128)?;
+        self.reward_per_token_stored = self.reward_per_token_stored.checked_add(increment)?;
+        Some(())
+    }
+}
+
+pub const REWARD_RATE_DENOMINATOR: u128 = 1_000_000_000;
+pub const REWARD_PER_TOKEN_PRECISION: u128 = 1_000_000_000_000;
+pub const SECONDS_PER_DAY: u128 = 24 * 60 * 60;
+pub const MAX_STAKE_MULTIPLIER_BPS: u16 = 50_000; // 5x
+
+/// Lock tier chosen per stake
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StakeTier {
+    Flexible,       // No lock, lower rate
+    Days30,
+    Days90,
+    Days180,
+    Days365,
+}
+
+impl StakeTier {
+    pub const COUNT: usize = 5;
+
+    pub fn lock_seconds(&self) -> i64 {
+        const DAY: i64 = 24 * 60 * 60;
+        match self {
+            StakeTier::Flexible => 0,
+            StakeTier::Days30 => 30 * DAY,
+            StakeTier::Days90 => 90 * DAY,
+            StakeTier::Days180 => 180 * DAY,
+            StakeTier::Days365 => 365 * DAY,
+        }
+    }
+}
+
+/// Governance-configured reward multipliers per stake tier
+#[account]
+pub struct StakingTierConfig {
+    pub multipliers_bps: [u16; StakeTier::COUNT],   // Indexed by `StakeTier`; 10_000 = 1x
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl StakingTierConfig {
+    pub const LEN: usize = 2 * StakeTier::COUNT + 8 + 1;
+
+    pub fn multiplier_for(&self, tier: StakeTier) -> u16 {
+        self.multipliers_bps[tier as usize]
+    }
+
+    /// Multipliers must be positive, capped, and never reward a shorter lock more
+    pub fn validate_multipliers(multipliers_bps: &[u16; StakeTier::COUNT]) -> bool {
+        multipliers_bps.iter().all(|m| *m > 0 && *m <= MAX_STAKE_MULTIPLIER_BPS)
+            && multipliers_bps.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    pub fn default_multipliers() -> [u16; StakeTier::COUNT] {
+        [5_000, 10_000, 12_500, 15_000, 20_000]
+    }
+}
+
+/// Auto-compounding vault that stakes on behalf of its depositors in a flexible position
+#[account]
+pub struct CompoundingVault {
+    pub pool: Pubkey,
+    pub total_shares: u64,
+    pub compound_interval: i64,             // Minimum seconds between cranks
+    pub last_compound_time: i64,
+    pub crank_incentive_bps: u16,           // Share of each compound paid to the cranker
+    pub total_compounded: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub receipt_mint: Option<Pubkey>,       // Liquid receipt token for vault shares, if enabled
+}
+
+impl CompoundingVault {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 2 + 8 + 8 + 1 + 33;
+
+    /// Shares minted for depositing `amount` into a vault holding `assets`; `None` if shares
+    /// are outstanding against an empty vault, since any price would dilute or wipe them
+    pub fn shares_for_deposit(&self, amount: u64, assets: u64) -> Option<u64> {
+        if self.total_shares == 0 {
+            return Some(amount);
+        }
+        u64::try_from((amount as u128).checked_mul(self.total_shares as u128)?.checked_div(assets as u128)?).ok()
+    }
+
+    /// Tokens redeemed for burning `shares` of a vault holding `assets`
+    pub fn assets_for_shares(&self, shares: u64, assets: u64) -> Option<u64> {
+        if self.total_shares == 0 {
+            return Some(0);
+        }
+        u64::try_from((shares as u128).checked_mul(assets as u128)? / self.total_shares as u128).ok()
+    }
 }
 
+/// A depositor's share balance in a compounding vault
+#[account]
+pub struct VaultDeposit {
+    pub vault: Pubkey,
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub deposited_at: i64,
+    pub bump: u8,
+}
+
+impl VaultDeposit {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 1;
+}
+
+pub const MAX_CRANK_INCENTIVE_BPS: u16 = 100; // 1%
+
 /// User staking position
 #[account]
 pub struct StakingPosition {
@@ -193,6 +826,115 @@ pub struct StakingPosition {
     pub rewards_claimed: u64,
     pub pending_rewards: u64,
     pub is_locked: bool,
+    pub reward_per_token_paid: u128,        // Pool accumulator value at the last settlement
+    pub bump: u8,
+    pub tier: StakeTier,
+    pub weighted_amount: u64,               // `amount` scaled by the tier multiplier
+    pub fee_revenue_per_token_paid: u128,   // Pool fee revenue accumulator at the last settlement
+    pub pending_fee_revenue: u64,           // Settled fee revenue in lamports, not yet claimed
+    pub last_deposit_time: i64,             // Last time tokens were added; gates per-token vote weight
+    pub fee_exempt: bool,                   // Compounding vault positions have no owner to pay SOL to, so they earn no fee revenue
+    pub vote_locked_until: i64,             // End of the latest mint or governance vote this position counts in
+}
+
+impl StakingPosition {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 8 + 8 + 1 + 16 + 1 + 1 + 8 + 16 + 8 + 8 + 1 + 8;
+
+    /// Move rewards and fee revenue earned since the last settlement into the pending balances
+    pub fn settle_rewards(&mut self, pool: &StakingPool) -> Option<()> {
+        let delta = pool.reward_per_token_stored.checked_sub(self.reward_per_token_paid)?;
+        let earned = (self.weighted_amount as u128)
+            .checked_mul(delta)?
+            .checked_div(REWARD_PER_TOKEN_PRECISION)?;
+        self.pending_rewards = self.pending_rewards.checked_add(u64::try_from(earned).ok()?)?;
+        self.reward_per_token_paid = pool.reward_per_token_stored;
+
+        if !self.fee_exempt {
+            let fee_delta = pool.fee_revenue_per_token_stored.checked_sub(self.fee_revenue_per_token_paid)?;
+            let fee_earned = (self.weighted_amount as u128)
+                .checked_mul(fee_delta)?
+                .checked_div(REWARD_PER_TOKEN_PRECISION)?;
+            self.pending_fee_revenue = self.pending_fee_revenue.checked_add(u64::try_from(fee_earned).ok()?)?;
+        }
+        self.fee_revenue_per_token_paid = pool.fee_revenue_per_token_stored;
+        Some(())
+    }
+
+    /// Set the staked amount and re-weight it in the pool totals; settle rewards first
+    pub fn set_amount(&mut self, pool: &mut StakingPool, amount: u64, multiplier_bps: u16) -> Option<()> {
+        let weighted_amount = u64::try_from(
+            (amount as u128).checked_mul(multiplier_bps as u128)? / 10_000
+        ).ok()?;
+        pool.total_staked = pool.total_staked.checked_sub(self.amount)?.checked_add(amount)?;
+        pool.total_weighted_staked = pool.total_weighted_staked
+            .checked_sub(self.weighted_amount)?
+            .checked_add(weighted_amount)?;
+        if !self.fee_exempt {
+            pool.fee_weighted_staked = pool.fee_weighted_staked
+                .checked_sub(self.weighted_amount)?
+                .checked_add(weighted_amount)?;
+        }
+        self.amount = amount;
+        self.weighted_amount = weighted_amount;
+        Some(())
+    }
+}
+
+/// Staker share of platform trading fees, held as SOL in the fee revenue vault
+#[account]
+pub struct FeeRevenuePool {
+    pub staking_pool: Pubkey,               // Governance token staking pool whose stakers earn the revenue
+    pub total_deposited: u64,
+    pub total_claimed: u64,
+    pub current_epoch: u64,
+    pub epoch_start: i64,
+    pub epoch_duration: i64,
+    pub epoch_revenue: u64,
+    pub epoch_claimed: u64,
+    pub vault_bump: u8,
+    pub bump: u8,
+}
+
+impl FeeRevenuePool {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+
+    /// Queue `amount` lamports for the pool's stakers. Deposits stream out until the next
+    /// epoch boundary, which later deposits in the same epoch do not move; call
+    /// `pool.update_rewards` first so earlier revenue is released at the old pace.
+    pub fn deposit(&mut self, pool: &mut StakingPool, amount: u64, current_time: i64) -> Option<()> {
+        self.total_deposited = self.total_deposited.checked_add(amount)?;
+        self.epoch_revenue = self.epoch_revenue.checked_add(amount)?;
+
+        pool.fee_revenue_pending = pool.fee_revenue_pending.checked_add(amount)?;
+        pool.fee_revenue_release_end = pool.fee_revenue_release_end.max(self.next_epoch_boundary(current_time)?);
+        Some(())
+    }
+
+    /// First epoch boundary after `current_time`, counting whole epochs from `epoch_start`
+    /// so it stays put even if the epoch has not been closed yet
+    pub fn next_epoch_boundary(&self, current_time: i64) -> Option<i64> {
+        let elapsed = current_time.checked_sub(self.epoch_start)?.max(0);
+        let epochs = elapsed.checked_div(self.epoch_duration)?.checked_add(1)?;
+        self.epoch_start.checked_add(epochs.checked_mul(self.epoch_duration)?)
+    }
+}
+
+/// Closed fee revenue epoch, kept for realized yield reporting
+#[account]
+pub struct FeeRevenueEpoch {
+    pub epoch: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub revenue: u64,                       // Lamports paid into the vault during the epoch
+    pub claimed: u64,                       // Lamports claimed by stakers during the epoch
+    pub total_staked: u64,
+    pub total_weighted_staked: u64,         // Fee-earning weighted stake; compounding vault positions excluded
+    pub revenue_per_token: u128,            // `revenue` per weighted token, scaled by REWARD_PER_TOKEN_PRECISION
+    pub bump: u8,
+}
+
+impl FeeRevenueEpoch {
+    pub const LEN: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 16 + 1;
 }
 
 /// Governance proposal
@@ -237,6 +979,16 @@ pub struct LaunchPass {
     pub used_for_token: Option<Pubkey>,
     pub created_at: i64,
     pub expires_at: Option<i64>,
+    pub transferable: bool,     // Soulbound passes are frozen in the holder's wallet
+    pub bump: u8,
+}
+
+impl LaunchPass {
+    pub const LEN: usize = 32 + 32 + 32
+        + (4 + TokenMetadata::MAX_NAME_LEN)
+        + (4 + TokenMetadata::MAX_SYMBOL_LEN)
+        + (4 + TokenMetadata::MAX_URI_LEN)
+        + 1 + (1 + 32) + 8 + (1 + 8) + 1 + 1;
 }
 
 /// Liquidity pool information
@@ -251,8 +1003,89 @@ pub struct LiquidityPool {
     pub created_at: i64,
     pub last_swap_time: i64,
     pub total_volume: u64,
+    pub bump: u8,
+    pub lp_mint: Pubkey,
+    pub sol_vault_bump: u8,
+}
+
+impl LiquidityPool {
+    pub const LEN: usize = 32 + 8 + 8 + 8 + 2 + 1 + 8 + 8 + 8 + 1 + 32 + 1;
 }
 
+/// Bonding-curve market used as the primary venue between launch and AMM graduation
+#[account]
+pub struct BondingCurve {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub virtual_sol_reserves: u64,          // Includes real SOL; sets the starting price
+    pub virtual_token_reserves: u64,        // Includes real tokens; must exceed the real supply
+    pub real_sol_reserves: u64,             // SOL held in the curve vault (excluding rent)
+    pub real_token_reserves: u64,           // Tokens left for sale in the curve vault
+    pub initial_real_token_reserves: u64,
+    pub total_volume: u64,
+    pub total_fees: u64,
+    pub complete: bool,                     // No more trading on the curve
+    pub created_at: i64,
+    pub last_trade_time: i64,
+    pub bump: u8,
+    pub sol_vault_bump: u8,
+    pub graduation_market_cap: u64,         // Market cap (lamports) at which the curve closes
+    pub lp_lock_seconds: i64,               // Delay before the LP tokens are burned; 0 burns them at graduation
+    pub graduated: bool,
+}
+
+impl BondingCurve {
+    pub const LEN: usize = 32 + 32 + 8 * 7 + 1 + 8 + 8 + 1 + 1 + 8 + 8 + 1;
+
+    /// Market cap in lamports at the current curve price
+    pub fn market_cap(&self, total_supply: u64) -> Option<u64> {
+        let numerator = (self.virtual_sol_reserves as u128).checked_mul(total_supply as u128)?;
+        u64::try_from(numerator.checked_div(self.virtual_token_reserves as u128)?).ok()
+    }
+
+    /// Tokens received for `sol_in` lamports on the constant-product virtual curve
+    pub fn tokens_out_for_sol(&self, sol_in: u64) -> Option<u64> {
+        let numerator = (self.virtual_token_reserves as u128).checked_mul(sol_in as u128)?;
+        let denominator = (self.virtual_sol_reserves as u128).checked_add(sol_in as u128)?;
+        u64::try_from(numerator.checked_div(denominator)?).ok()
+    }
+
+    /// Lamports required to buy exactly `tokens_out` tokens (rounded up)
+    pub fn sol_in_for_tokens(&self, tokens_out: u64) -> Option<u64> {
+        let remaining = (self.virtual_token_reserves as u128).checked_sub(tokens_out as u128)?;
+        if remaining == 0 {
+            return None;
+        }
+        let numerator = (self.virtual_sol_reserves as u128).checked_mul(tokens_out as u128)?;
+        u64::try_from(numerator.checked_add(remaining - 1)?.checked_div(remaining)?).ok()
+    }
+
+    /// Lamports received for selling `tokens_in` tokens back to the curve
+    pub fn sol_out_for_tokens(&self, tokens_in: u64) -> Option<u64> {
+        let numerator = (self.virtual_sol_reserves as u128).checked_mul(tokens_in as u128)?;
+        let denominator = (self.virtual_token_reserves as u128).checked_add(tokens_in as u128)?;
+        u64::try_from(numerator.checked_div(denominator)?).ok()
+    }
+}
+
+/// A wallet's last trade on one curve, for the anti-bot cooldown
+#[account]
+pub struct CurveTrader {
+    pub mint: Pubkey,
+    pub trader: Pubkey,
+    pub last_trade_time: i64,
+    pub bump: u8,
+}
+
+impl CurveTrader {
+    pub const LEN: usize = 32 + 32 + 8 + 1;
+}
+
+/// Bonding curve limits
+pub const MIN_VIRTUAL_SOL_RESERVES: u64 = 1_000_000_000;      // 1 SOL
+pub const MAX_VIRTUAL_SOL_RESERVES: u64 = 1_000_000_000_000;  // 1,000 SOL
+pub const GRADUATION_MARKET_CAP: u64 = 400_000_000_000;     // 400 SOL
+
 /// Anti-bot protection configuration
 #[account]
 pub struct AntiBotConfig {
@@ -265,6 +1098,16 @@ pub struct AntiBotConfig {
     pub whitelisted_addresses: Vec<Pubkey>,
     pub max_wallet_percentage: u8,
     pub max_transaction_percentage: u8,
+    pub bump: u8,
+}
+
+impl AntiBotConfig {
+    pub const MAX_LISTED_ADDRESSES: usize = 50;
+
+    pub const LEN: usize = 32 + 1 + 8 + 8 + 8
+        + (4 + 32 * Self::MAX_LISTED_ADDRESSES)
+        + (4 + 32 * Self::MAX_LISTED_ADDRESSES)
+        + 1 + 1 + 1;
 }
 
 /// Suspicious activity report
@@ -339,6 +1182,42 @@ pub enum ProposalType {
     TreasuryAllocation,
     GovernanceRule,
     EmergencyAction,
+    SymbolRelease {            // Free a squatted ticker after its token failed
+        normalized_symbol: String,
+    },
+    CreatorPolicyUpdate {      // Replace the creator policy tiers
+        tiers: Vec<CreatorTier>,
+    },
+    VestingRevocation {        // Revoke a rugging creator's vesting
+        vesting: Pubkey,
+        destination: RevocationDestination,
+    },
+    VestingTransferPolicyUpdate { // Change the position holding period
+        min_holding_period: i64,
+    },
+    StakingTierUpdate {        // Replace the stake tier reward multipliers
+        multipliers_bps: [u16; StakeTier::COUNT],
+    },
+    CreatorSlash {             // Slash a rugging creator's stake
+        creator: Pubkey,
+        amount: u64,           // Lamports taken from the stake escrow
+    },
+}
+
+impl ProposalType {
+    /// Largest variant is a full set of creator policy tiers
+    pub const MAX_LEN: usize = 1 + (4 + CreatorTier::LEN * CreatorPolicy::MAX_TIERS);
+
+    /// Variable-length payloads fit in `MAX_LEN`
+    pub fn is_within_bounds(&self) -> bool {
+        match self {
+            ProposalType::SymbolRelease { normalized_symbol } => {
+                normalized_symbol.len() <= SymbolRegistry::MAX_NORMALIZED_LEN
+            },
+            ProposalType::CreatorPolicyUpdate { tiers } => tiers.len() <= CreatorPolicy::MAX_TIERS,
+            _ => true,
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
@@ -359,8 +1238,61 @@ pub enum ActivityType {
     Other,
 }
 
+/// Split applied when a creator's stake is slashed
+#[account]
+pub struct SlashingConfig {
+    pub compensation_share_bps: u16,        // Share sent to the holders' compensation pool; rest to treasury
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl SlashingConfig {
+    pub const LEN: usize = 2 + 8 + 1;
+}
+
+/// Slashed creator stake set aside for holders of a rugged token; lamports are held in the account
+#[account]
+pub struct CompensationPool {
+    pub mint: Pubkey,
+    pub creator: Pubkey,
+    pub total_received: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub bump: u8,
+    pub merkle_root: [u8; 32],              // Root over keccak(holder || lamports) leaves; zero until posted
+    pub snapshot_slot: u64,
+    pub total_claimed: u64,
+    pub claim_count: u32,
+    pub claim_deadline: i64,                // Unclaimed lamports can be swept to the treasury after this
+    pub swept: bool,
+}
+
+impl CompensationPool {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 8 + 1 + 32 + 8 + 8 + 4 + 8 + 1;
+
+    /// Holders have been snapshotted; later slashes no longer reach this pool
+    pub fn root_posted(&self) -> bool {
+        self.merkle_root != [0u8; 32]
+    }
+}
+
+/// Receipt marking a holder's compensation share as claimed
+#[account]
+pub struct CompensationClaim {
+    pub pool: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub claimed_at: i64,
+}
+
+impl CompensationClaim {
+    pub const LEN: usize = 32 + 32 + 8 + 8;
+}
+
 /// Constants
 pub const MIN_STAKE_AMOUNT: u64 = 500_000_000; // 0.5 SOL
+pub const MAX_BAN_REASON_LENGTH: usize = 200;
+pub const CREATOR_DEREGISTRATION_COOLDOWN: i64 = 30 * 24 * 60 * 60; // 30 days, still slashable
 pub const MAX_CREATOR_PERCENT: u8 = 20; // 20% max for creator
 pub const MIN_VESTING_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
 pub const MAX_VESTING_PERIOD: i64 = 365 * 24 * 60 * 60; // 1 year
@@ -470,6 +1402,10 @@ pub struct GovernanceToken {
     pub updated_at: i64,
 }
 
+impl GovernanceToken {
+    pub const LEN: usize = 32 + 32 + 8 + 8 + 1 + 8 + 8;
+}
+
 /// Governance token holder
 #[account]
 pub struct TokenHolder {
@@ -516,6 +1452,19 @@ pub struct GovernanceProposal {
     pub updated_at: i64,
 }
 
+impl GovernanceProposal {
+    pub const MAX_TITLE_LEN: usize = 200;
+    pub const MAX_DESCRIPTION_LEN: usize = 2000;
+
+    pub const LEN: usize = 8 + 32 + (4 + Self::MAX_TITLE_LEN) + (4 + Self::MAX_DESCRIPTION_LEN)
+        + ProposalType::MAX_LEN + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + (1 + 8) + (1 + 32) + 8 + 8;
+
+    /// Voting has closed, quorum was met and yes votes outnumber no votes
+    pub fn has_passed(&self, current_time: i64) -> bool {
+        current_time > self.end_time && self.quorum_met && self.yes_votes > self.no_votes
+    }
+}
+
 /// Individual vote on a proposal
 #[account]
 pub struct Vote {
@@ -526,6 +1475,10 @@ pub struct Vote {
     pub voted_at: i64,
 }
 
+impl Vote {
+    pub const LEN: usize = 8 + 32 + 1 + 8 + 8;
+}
+
 /// Vote types
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
 pub enum VoteType {
@@ -547,6 +1500,11 @@ pub struct GovernanceConfig {
     pub is_active: bool,
     pub created_at: i64,
     pub updated_at: i64,
+    pub proposal_count: u64,    // Proposals created; seeds the next proposal PDA
+}
+
+impl GovernanceConfig {
+    pub const LEN: usize = 32 + 32 + 8 + 1 + 8 + 8 + 8 + 1 + 8 + 8 + 8;
 }
 
 /// Circuit breaker for extreme price movements and volume spikes
@@ -930,6 +1888,7 @@ pub struct SuspiciousActivityAlert {
     pub investigator: Option<Pubkey>,    // Assigned investigator
     pub investigation_notes: String,     // Investigation notes
     pub action_taken: String,            // Action taken
+    pub slashed: bool,                   // Set once the alert has backed a creator stake slash
     
     pub updated_at: i64,
 }
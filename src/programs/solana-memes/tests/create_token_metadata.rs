@@ -0,0 +1,279 @@
+//! Runs `create_token` against the real Token Metadata program.
+//!
+//! Needs the program binaries in `tests/fixtures` before `cargo test-sbf`:
+//!   anchor build && cp target/deploy/solana_memes.so tests/fixtures/
+//!   solana program dump -u m metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s tests/fixtures/mpl_token_metadata.so
+
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::get_associated_token_address;
+use mpl_token_metadata::accounts::Metadata;
+use solana_memes::state::{
+    CreatorPolicy, CreatorProfile, LaunchPass, SymbolRegistry, TokenMetadata, VestingBeneficiary,
+    VestingSchedule, MIN_VESTING_PERIOD, MIN_VIRTUAL_SOL_RESERVES,
+};
+use solana_program::program_pack::Pack;
+use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    compute_budget::ComputeBudgetInstruction,
+    instruction::Instruction,
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_program,
+    sysvar,
+    transaction::Transaction,
+};
+
+const NAME: &str = "Doge Moon";
+const SYMBOL: &str = "DMOON";
+const URI: &str = "https://example.com/dmoon.json";
+
+/// Serialize an Anchor account (discriminator included) into a program-owned account of `space` bytes
+fn program_account<T: AccountSerialize>(account: &T, space: usize) -> Account {
+    let mut data = Vec::with_capacity(space);
+    account.try_serialize(&mut data).unwrap();
+    data.resize(space, 0);
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner: solana_memes::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Pack an SPL Token state into a token-program-owned account
+fn token_program_account<T: Pack>(state: T) -> Account {
+    let mut data = vec![0; T::LEN];
+    state.pack_into_slice(&mut data);
+    Account {
+        lamports: LAMPORTS_PER_SOL,
+        data,
+        owner: spl_token::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Mint for the launch pass bound to a creator
+fn launch_pass_mint(creator: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"test_launch_pass", creator.as_ref()], &solana_memes::ID).0
+}
+
+/// Program test with both programs loaded and a registered creator
+async fn setup(creator: &Keypair) -> ProgramTestContext {
+    let mut program_test = ProgramTest::new("solana_memes", solana_memes::ID, None);
+    program_test.add_program("mpl_token_metadata", mpl_token_metadata::ID, None);
+    program_test.prefer_bpf(true);
+
+    program_test.add_account(
+        creator.pubkey(),
+        Account::new(100 * LAMPORTS_PER_SOL, 0, &system_program::ID),
+    );
+
+    let (creator_profile, profile_bump) = Pubkey::find_program_address(
+        &[b"creator_profile", creator.pubkey().as_ref()],
+        &solana_memes::ID,
+    );
+    let profile = CreatorProfile {
+        is_registered: true,
+        owner: creator.pubkey(),
+        stake_amount: LAMPORTS_PER_SOL / 2,
+        last_creation_ts: 0,
+        reputation_score: 0,
+        total_tokens_created: 0,
+        successful_tokens: 0,
+        failed_tokens: 0,
+        total_volume: 0,
+        is_banned: false,
+        ban_reason: String::new(),
+        launch_pass_required: true,
+        launch_pass_mint: Some(launch_pass_mint(&creator.pubkey())),
+        max_allocation_percent: 3,
+        total_profit_shared: 0,
+        weekly_creation_count: 0,
+        last_week_reset: 0,
+        deregistration_requested_at: None,
+        bump: profile_bump,
+    };
+    program_test.add_account(
+        creator_profile,
+        program_account(&profile, 8 + CreatorProfile::LEN),
+    );
+
+    let (creator_policy, bump) = Pubkey::find_program_address(&[b"creator_policy"], &solana_memes::ID);
+    let policy = CreatorPolicy {
+        tiers: CreatorPolicy::default_tiers(),
+        updated_at: 0,
+        bump,
+    };
+    program_test.add_account(creator_policy, program_account(&policy, 8 + CreatorPolicy::LEN));
+
+    // Unused launch pass NFT held by the creator
+    let pass_mint = launch_pass_mint(&creator.pubkey());
+    let (launch_pass, bump) =
+        Pubkey::find_program_address(&[b"launch_pass", pass_mint.as_ref()], &solana_memes::ID);
+    let pass = LaunchPass {
+        mint: pass_mint,
+        owner: creator.pubkey(),
+        creator: Pubkey::new_unique(),
+        name: "Launch Pass".to_string(),
+        symbol: "PASS".to_string(),
+        uri: String::new(),
+        is_used: false,
+        used_for_token: None,
+        created_at: 0,
+        expires_at: None,
+        transferable: true,
+        bump,
+    };
+    program_test.add_account(launch_pass, program_account(&pass, 8 + LaunchPass::LEN));
+    program_test.add_account(
+        pass_mint,
+        token_program_account(spl_token::state::Mint {
+            mint_authority: None.into(),
+            supply: 1,
+            decimals: 0,
+            is_initialized: true,
+            freeze_authority: Some(launch_pass).into(),
+        }),
+    );
+    program_test.add_account(
+        get_associated_token_address(&creator.pubkey(), &pass_mint),
+        token_program_account(spl_token::state::Account {
+            mint: pass_mint,
+            owner: creator.pubkey(),
+            amount: 1,
+            state: spl_token::state::AccountState::Initialized,
+            ..Default::default()
+        }),
+    );
+
+    program_test.start_with_context().await
+}
+
+fn create_token_ix(creator: &Pubkey, mint: &Pubkey, immutable_metadata: bool) -> Instruction {
+    let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &solana_memes::ID).0;
+    let normalized_symbol = SymbolRegistry::normalize(SYMBOL);
+    let creator_allocation = pda(&[b"creator_allocation", mint.as_ref()]);
+    let bonding_curve = pda(&[b"bonding_curve", mint.as_ref()]);
+    let pass_mint = launch_pass_mint(creator);
+
+    let accounts = solana_memes::accounts::CreateToken {
+        creator_profile: pda(&[b"creator_profile", creator.as_ref()]),
+        creator_policy: pda(&[b"creator_policy"]),
+        creator: *creator,
+        mint: *mint,
+        creator_token_account: get_associated_token_address(creator, mint),
+        allocation_vault: get_associated_token_address(&creator_allocation, mint),
+        creator_allocation,
+        token_metadata: pda(&[b"token_metadata", mint.as_ref()]),
+        staking_pool: pda(&[b"staking_pool", mint.as_ref()]),
+        anti_bot_config: pda(&[b"anti_bot_config", mint.as_ref()]),
+        liquidity_pool: pda(&[b"liquidity_pool", mint.as_ref()]),
+        bonding_curve,
+        curve_token_vault: get_associated_token_address(&bonding_curve, mint),
+        curve_sol_vault: pda(&[b"bonding_curve_sol_vault", mint.as_ref()]),
+        symbol_registry: pda(&[b"symbol_registry", normalized_symbol.as_bytes()]),
+        metaplex_metadata: Metadata::find_pda(mint).0,
+        launch_pass: pda(&[b"launch_pass", pass_mint.as_ref()]),
+        launch_pass_token_account: get_associated_token_address(creator, &pass_mint),
+        token_program: anchor_spl::token::ID,
+        associated_token_program: anchor_spl::associated_token::ID,
+        token_metadata_program: mpl_token_metadata::ID,
+        system_program: system_program::ID,
+        rent: sysvar::rent::ID,
+    };
+    let data = solana_memes::instruction::CreateToken {
+        name: NAME.to_string(),
+        symbol: SYMBOL.to_string(),
+        uri: URI.to_string(),
+        decimals: 6,
+        total_supply: 1_000_000_000_000,
+        creator_percent: 3,
+        beneficiaries: vec![VestingBeneficiary {
+            wallet: *creator,
+            share_bps: 300,
+            cliff_seconds: 0,
+            vesting_seconds: MIN_VESTING_PERIOD,
+            schedule: VestingSchedule::CliffLinear,
+        }],
+        immutable_metadata,
+        fixed_supply: Some(true),
+        normalized_symbol,
+        virtual_sol_reserves: MIN_VIRTUAL_SOL_RESERVES,
+        virtual_token_reserves: 1_073_000_000_000,
+        lp_lock_seconds: 0,
+    };
+
+    Instruction {
+        program_id: solana_memes::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Launch a token and return its Metaplex metadata
+async fn launch(immutable_metadata: bool) -> (Pubkey, Pubkey, Metadata, TokenMetadata) {
+    let creator = Keypair::new();
+    let mint = Keypair::new();
+    let mut context = setup(&creator).await;
+
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(1_400_000),
+            create_token_ix(&creator.pubkey(), &mint.pubkey(), immutable_metadata),
+        ],
+        Some(&creator.pubkey()),
+        &[&creator, &mint],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await.unwrap();
+
+    let (metadata_pda, _) = Metadata::find_pda(&mint.pubkey());
+    let metadata_account = context.banks_client
+        .get_account(metadata_pda)
+        .await
+        .unwrap()
+        .expect("metadata account created");
+    assert_eq!(metadata_account.owner, mpl_token_metadata::ID);
+    let metadata = Metadata::from_bytes(&metadata_account.data).unwrap();
+
+    let (token_metadata_pda, _) =
+        Pubkey::find_program_address(&[b"token_metadata", mint.pubkey().as_ref()], &solana_memes::ID);
+    let token_metadata_account = context.banks_client
+        .get_account(token_metadata_pda)
+        .await
+        .unwrap()
+        .expect("token metadata account created");
+    let token_metadata =
+        <TokenMetadata as anchor_lang::AccountDeserialize>::try_deserialize(&mut token_metadata_account.data.as_slice())
+            .unwrap();
+
+    (creator.pubkey(), mint.pubkey(), metadata, token_metadata)
+}
+
+#[tokio::test]
+async fn create_token_creates_metaplex_metadata() {
+    let (creator, mint, metadata, token_metadata) = launch(false).await;
+
+    // Metaplex pads the strings with NUL bytes up to their maximum length
+    assert_eq!(metadata.mint, mint);
+    assert_eq!(metadata.update_authority, creator);
+    assert_eq!(metadata.name.trim_end_matches('\0'), NAME);
+    assert_eq!(metadata.symbol.trim_end_matches('\0'), SYMBOL);
+    assert_eq!(metadata.uri.trim_end_matches('\0'), URI);
+    assert!(metadata.is_mutable);
+
+    assert_eq!(token_metadata.metaplex_metadata, Metadata::find_pda(&mint).0);
+    assert!(token_metadata.metadata_is_mutable);
+}
+
+#[tokio::test]
+async fn create_token_can_lock_metaplex_metadata() {
+    let (_, _, metadata, token_metadata) = launch(true).await;
+
+    assert!(!metadata.is_mutable);
+    assert!(!token_metadata.metadata_is_mutable);
+}
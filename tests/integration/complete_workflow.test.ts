@@ -15,6 +15,7 @@ describe('Complete Token Creation and LBM Workflow', () => {
     let participant1TokenAccount: PublicKey;
     let participant2TokenAccount: PublicKey;
     let creatorProfile: PublicKey;
+    let creatorStakeEscrow: PublicKey;
     let tokenMetadata: PublicKey;
     let vesting: PublicKey;
     let lbmPool: PublicKey;
@@ -85,6 +86,11 @@ describe('Complete Token Creation and LBM Workflow', () => {
             program.programId
         );
 
+        [creatorStakeEscrow] = PublicKey.findProgramAddressSync(
+            [Buffer.from('creator_stake'), creator.publicKey.toBuffer()],
+            program.programId
+        );
+
         [tokenMetadata] = PublicKey.findProgramAddressSync(
             [Buffer.from('token_metadata'), tokenMint.toBuffer()],
             program.programId
@@ -120,7 +126,7 @@ describe('Complete Token Creation and LBM Workflow', () => {
                 .accounts({
                     creator: creator.publicKey,
                     creatorProfile,
-                    treasury,
+                    stakeEscrow: creatorStakeEscrow,
                     systemProgram: SystemProgram.programId,
                 })
                 .signers([creator])
@@ -266,12 +272,9 @@ describe('Complete Token Creation and LBM Workflow', () => {
                 .accounts({
                     creator: creator.publicKey,
                     vesting,
-                    tokenMint,
+                    mint: tokenMint,
                     tokenMetadata,
-                    treasury,
-                    stakingRewards,
                     tokenProgram: TOKEN_PROGRAM_ID,
-                    systemProgram: SystemProgram.programId,
                 })
                 .signers([creator])
                 .rpc();
@@ -289,12 +292,27 @@ describe('Complete Token Creation and LBM Workflow', () => {
 
             const tradeAmount = new BN(1_000_000_000); // 1 SOL trade
 
+            const [feeRevenuePool] = PublicKey.findProgramAddressSync(
+                [Buffer.from('fee_revenue_pool')],
+                program.programId
+            );
+            const [feeRevenueVault] = PublicKey.findProgramAddressSync(
+                [Buffer.from('fee_revenue_vault')],
+                program.programId
+            );
+            const [stakingPool] = PublicKey.findProgramAddressSync(
+                [Buffer.from('staking_pool'), tokenMint.toBuffer()],
+                program.programId
+            );
+
             await program.methods
                 .collectTradingFee(tradeAmount)
                 .accounts({
                     treasury,
                     stakingRewards,
-                    tokenProgram: TOKEN_PROGRAM_ID,
+                    feeRevenuePool,
+                    stakingPool,
+                    feeRevenueVault,
                     systemProgram: SystemProgram.programId,
                 })
                 .rpc();
@@ -309,31 +327,34 @@ describe('Complete Token Creation and LBM Workflow', () => {
             console.log('✅ Trading fee collection working correctly!');
         });
 
-        it('should validate creator limits', async () => {
-            console.log('Testing creator limit validation...');
+        it('should enforce creator policy limits on token creation', async () => {
+            console.log('Testing creator policy enforcement...');
 
-            await program.methods
-                .validateCreatorLimits()
-                .accounts({
-                    creator: creator.publicKey,
-                    creatorProfile,
-                    systemProgram: SystemProgram.programId,
-                })
-                .signers([creator])
-                .rpc();
+            const [creatorPolicy] = PublicKey.findProgramAddressSync(
+                [Buffer.from('creator_policy')],
+                program.programId
+            );
 
-            // Verify limits are enforced
+            // Limits are applied by create_token itself
+            const policyAccount = await program.account.creatorPolicy.fetch(creatorPolicy);
             const creatorAccount = await program.account.creatorProfile.fetch(creatorProfile);
-            expect(creatorAccount.weeklyCreations).to.be.greaterThan(0);
-            expect(creatorAccount.weeklyCreations).to.be.lessThanOrEqual(2); // Max 2 per week
+            const tier = policyAccount.tiers[0];
 
-            console.log('✅ Creator limit validation working correctly!');
+            expect(creatorAccount.weeklyCreationCount).to.be.greaterThan(0);
+            expect(creatorAccount.weeklyCreationCount).to.be.lessThanOrEqual(tier.maxWeeklyCreations);
+            expect(creatorAccount.maxAllocationPercent).to.be.lessThanOrEqual(7); // Platform max
+
+            console.log('✅ Creator policy enforcement working correctly!');
         });
     });
 
     describe('Error Handling', () => {
         it('should handle insufficient funds gracefully', async () => {
             const poorCreator = Keypair.generate();
+            const [poorCreatorStakeEscrow] = PublicKey.findProgramAddressSync(
+                [Buffer.from('creator_stake'), poorCreator.publicKey.toBuffer()],
+                program.programId
+            );
 
             try {
                 await program.methods
@@ -341,7 +362,7 @@ describe('Complete Token Creation and LBM Workflow', () => {
                     .accounts({
                         creator: poorCreator.publicKey,
                         creatorProfile,
-                        treasury,
+                        stakeEscrow: poorCreatorStakeEscrow,
                         systemProgram: SystemProgram.programId,
                     })
                     .signers([poorCreator])
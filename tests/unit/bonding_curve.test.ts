@@ -0,0 +1,141 @@
+import { describe, it, expect, beforeEach } from '@jest/globals';
+import { Keypair, PublicKey } from '@solana/web3.js';
+
+describe('Bonding Curve Tests', () => {
+  let mint: Keypair;
+  let programId: PublicKey;
+
+  const tokensOutForSol = (virtualSol: bigint, virtualToken: bigint, solIn: bigint) =>
+    (virtualToken * solIn) / (virtualSol + solIn);
+
+  const solOutForTokens = (virtualSol: bigint, virtualToken: bigint, tokensIn: bigint) =>
+    (virtualSol * tokensIn) / (virtualToken + tokensIn);
+
+  beforeEach(() => {
+    mint = Keypair.generate();
+    programId = Keypair.generate().publicKey;
+  });
+
+  describe('pda_layout', () => {
+    it('should derive the curve and SOL vault PDAs from the mint', () => {
+      const [bondingCurve] = PublicKey.findProgramAddressSync(
+        [Buffer.from('bonding_curve'), mint.publicKey.toBuffer()],
+        programId
+      );
+      const [solVault] = PublicKey.findProgramAddressSync(
+        [Buffer.from('bonding_curve_sol_vault'), mint.publicKey.toBuffer()],
+        programId
+      );
+
+      expect(bondingCurve.equals(solVault)).toBe(false);
+    });
+  });
+
+  describe('create_token', () => {
+    it('should escrow the whole public allocation on the curve at launch', () => {
+      const totalSupply = 1_000_000_000_000n;
+      const creatorPercent = 3n;
+      const creatorAmount = (totalSupply * creatorPercent) / 100n;
+      const publicAmount = totalSupply - creatorAmount;
+      const creatorTokenBalance = totalSupply - creatorAmount - publicAmount;
+
+      expect(creatorTokenBalance).toBe(0n);
+      expect(publicAmount).toBe(970_000_000_000n);
+    });
+  });
+
+  describe('pricing', () => {
+    const virtualSol = 30_000_000_000n; // 30 SOL
+    const virtualToken = 1_073_000_000_000_000n;
+
+    it('should give fewer tokens per SOL as the price rises', () => {
+      const first = tokensOutForSol(virtualSol, virtualToken, 1_000_000_000n);
+      const second = tokensOutForSol(virtualSol + 1_000_000_000n, virtualToken - first, 1_000_000_000n);
+
+      expect(second < first).toBe(true);
+    });
+
+    it('should never return more SOL on a sell than was paid on the buy', () => {
+      const solIn = 5_000_000_000n;
+      const tokensOut = tokensOutForSol(virtualSol, virtualToken, solIn);
+      const solBack = solOutForTokens(virtualSol + solIn, virtualToken - tokensOut, tokensOut);
+
+      expect(solBack <= solIn).toBe(true);
+    });
+
+    it('should take the platform trading fee per mille', () => {
+      const tradingFeePercentage = 12n; // 1.2%
+      const solAmount = 1_000_000_000n;
+      const fee = (solAmount * tradingFeePercentage) / 1000n;
+
+      expect(fee).toBe(12_000_000n);
+    });
+  });
+
+  describe('graduate', () => {
+    it('should close the curve at the graduation market cap', () => {
+      const graduationMarketCap = 400_000_000_000n; // 400 SOL
+      const totalSupply = 1_000_000_000_000_000n;
+      const virtualSol = 450_000_000_000n;
+      const virtualToken = 1_000_000_000_000_000n;
+      const marketCap = (virtualSol * totalSupply) / virtualToken;
+
+      expect(marketCap >= graduationMarketCap).toBe(true);
+    });
+
+    it('should seed the pool at the closing curve price and burn the excess', () => {
+      const realSol = 85_000_000_000n;
+      const realToken = 300_000_000_000_000n;
+      const virtualSol = 115_000_000_000n;
+      const virtualToken = 280_000_000_000_000n;
+
+      const tokensAtPrice = (realSol * virtualToken) / virtualSol;
+      const poolTokens = tokensAtPrice < realToken ? tokensAtPrice : realToken;
+      const burned = realToken - poolTokens;
+
+      expect(poolTokens + burned).toBe(realToken);
+      expect(poolTokens * virtualSol <= realSol * virtualToken).toBe(true);
+    });
+
+    it('should burn LP tokens when no lock period is set', () => {
+      const lpLockSeconds = 0;
+      const liquidityLockEnd = lpLockSeconds === 0 ? Number.MAX_SAFE_INTEGER : Date.now() / 1000 + lpLockSeconds;
+
+      expect(liquidityLockEnd).toBe(Number.MAX_SAFE_INTEGER);
+    });
+
+    it('should burn time-locked LP tokens only after the lock ends', () => {
+      const liquidityLockEnd = 1_000;
+      const canBurn = (now: number) => now >= liquidityLockEnd;
+
+      expect(canBurn(999)).toBe(false);
+      expect(canBurn(1_000)).toBe(true);
+    });
+  });
+
+  describe('anti_bot', () => {
+    it('should reject buys above the max wallet percentage', () => {
+      const totalSupply = 1_000_000_000;
+      const maxWalletPercentage = 5;
+      const balanceAfter = 60_000_000;
+
+      try {
+        if (balanceAfter > (totalSupply * maxWalletPercentage) / 100) {
+          throw new Error('Max wallet percentage exceeded');
+        }
+      } catch (error) {
+        expect(error.message).toContain('Max wallet percentage exceeded');
+      }
+    });
+
+    it('should enforce the cooldown between a wallet\'s curve trades', () => {
+      const cooldownPeriod = 300;
+      const canTrade = (lastTradeTime: number, now: number) =>
+        lastTradeTime === 0 || now >= lastTradeTime + cooldownPeriod;
+
+      expect(canTrade(0, 1_000)).toBe(true); // First trade
+      expect(canTrade(1_000, 1_200)).toBe(false);
+      expect(canTrade(1_000, 1_300)).toBe(true);
+    });
+  });
+});
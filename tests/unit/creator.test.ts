@@ -67,6 +67,16 @@ describe('Creator Registration Tests', () => {
       }
     });
 
+    it('should derive exactly one profile per creator', () => {
+      const programId = Keypair.generate().publicKey;
+      const profileFor = (wallet: PublicKey) =>
+        PublicKey.findProgramAddressSync([Buffer.from('creator_profile'), wallet.toBuffer()], programId)[0];
+
+      // A second registration lands on the same address, so it cannot shadow the staked profile
+      expect(profileFor(creator.publicKey).equals(profileFor(creator.publicKey))).toBe(true);
+      expect(profileFor(creator.publicKey).equals(profileFor(Keypair.generate().publicKey))).toBe(false);
+    });
+
     it('should initialize creator profile correctly', async () => {
       const stakeAmount = 500_000_000;
       
@@ -83,7 +93,7 @@ describe('Creator Registration Tests', () => {
         totalVolume: 0,
         isBanned: false,
         banReason: '',
-        launchPassRequired: false,
+        launchPassRequired: true,
         launchPassMint: null
       };
 
@@ -111,4 +121,189 @@ describe('Creator Registration Tests', () => {
       expect(providedStake).toBeGreaterThanOrEqual(minStake);
     });
   });
+
+  describe('creator_policy', () => {
+    const DAY = 24 * 60 * 60;
+    const tiers = [
+      { minReputationScore: 0, minSuccessfulTokens: 0, maxAllocationPercent: 3, creationCooldown: 30 * DAY, maxWeeklyCreations: 1 },
+      { minReputationScore: 100, minSuccessfulTokens: 1, maxAllocationPercent: 5, creationCooldown: 7 * DAY, maxWeeklyCreations: 1 },
+      { minReputationScore: 500, minSuccessfulTokens: 3, maxAllocationPercent: 7, creationCooldown: DAY, maxWeeklyCreations: 2 }
+    ];
+    const tierFor = (reputationScore: number, successfulTokens: number) =>
+      tiers.filter((t) => reputationScore >= t.minReputationScore && successfulTokens >= t.minSuccessfulTokens).pop();
+
+    it('should pick the highest tier the creator qualifies for', () => {
+      expect(tierFor(0, 0)?.maxAllocationPercent).toBe(3);
+      expect(tierFor(150, 1)?.maxAllocationPercent).toBe(5);
+      expect(tierFor(600, 2)?.maxAllocationPercent).toBe(5); // Not enough successful tokens
+      expect(tierFor(600, 5)?.maxAllocationPercent).toBe(7);
+    });
+
+    it('should reject creators with negative reputation', () => {
+      expect(tierFor(-10, 4)).toBeUndefined();
+    });
+
+    it('should enforce the tier cooldown and weekly cap', () => {
+      const tier = tierFor(0, 0)!;
+      const now = Math.floor(Date.now() / 1000);
+      const lastCreationTs = now - 10 * DAY;
+
+      expect(now - lastCreationTs >= tier.creationCooldown).toBe(false);
+      expect(1 < tier.maxWeeklyCreations).toBe(false);
+    });
+  });
+
+  describe('slash_creator_stake', () => {
+    const split = (amount: number, compensationShareBps: number) => {
+      const compensation = Math.floor((amount * compensationShareBps) / 10_000);
+      return { compensation, treasury: amount - compensation };
+    };
+
+    it('should split the slash between compensation pool and treasury', () => {
+      const { compensation, treasury } = split(500_000_000, 7_000);
+
+      expect(compensation).toBe(350_000_000);
+      expect(treasury).toBe(150_000_000);
+    });
+
+    it('should reject slashing more than the escrowed stake', () => {
+      const escrowBalance = 500_000_000;
+      const amount = 600_000_000;
+
+      try {
+        if (amount > escrowBalance) {
+          throw new Error('Slash exceeds creator stake');
+        }
+      } catch (error) {
+        expect(error.message).toContain('exceeds creator stake');
+      }
+    });
+
+    it('should only accept alerts resolved with a penalty or ban', () => {
+      const slashable = (alert: { status: string; resolution: string | null }) =>
+        alert.status === 'Resolved' && (alert.resolution === 'Penalty' || alert.resolution === 'Ban');
+
+      expect(slashable({ status: 'Resolved', resolution: 'Ban' })).toBe(true);
+      expect(slashable({ status: 'FalsePositive', resolution: 'NoAction' })).toBe(false);
+      expect(slashable({ status: 'Open', resolution: null })).toBe(false);
+    });
+
+    it('should let each alert back only one slash', () => {
+      const alert = { status: 'Resolved', resolution: 'Ban', slashed: false };
+      const slash = () => {
+        if (alert.slashed) {
+          throw new Error('Alert already used for a slash');
+        }
+        alert.slashed = true;
+      };
+
+      slash();
+      expect(slash).toThrow('Alert already used for a slash');
+    });
+
+    it('should send later slashes to the treasury once compensation is snapshotted', () => {
+      const rootPosted = true;
+      const amount = 500_000_000;
+      const compensation = rootPosted ? 0 : split(amount, 7_000).compensation;
+
+      expect(compensation).toBe(0);
+      expect(amount - compensation).toBe(amount);
+    });
+  });
+
+  describe('compensation_pool', () => {
+    it('should cap claims at the lamports the pool received', () => {
+      const pool = { totalReceived: 350_000_000, totalClaimed: 300_000_000 };
+      const amount = 100_000_000;
+
+      try {
+        if (pool.totalClaimed + amount > pool.totalReceived) {
+          throw new Error('Insufficient funds');
+        }
+      } catch (error) {
+        expect(error.message).toContain('Insufficient funds');
+      }
+    });
+
+    it('should sweep only the unclaimed remainder after the deadline', () => {
+      const pool = { totalReceived: 350_000_000, totalClaimed: 120_000_000, claimDeadline: 1_000, swept: false };
+      const now = 1_001;
+
+      expect(now > pool.claimDeadline).toBe(true);
+      expect(pool.totalReceived - pool.totalClaimed).toBe(230_000_000);
+    });
+
+    it('should ban the creator with the given reason', () => {
+      const profile = { stakeAmount: 500_000_000, isBanned: false, banReason: '' };
+      const amount = 500_000_000;
+
+      profile.stakeAmount -= amount;
+      profile.isBanned = true;
+      profile.banReason = 'Liquidity pulled after launch';
+
+      expect(profile.stakeAmount).toBe(0);
+      expect(profile.isBanned).toBe(true);
+      expect(profile.banReason).toContain('Liquidity');
+    });
+  });
+
+  describe('creator_deregistration', () => {
+    const DAY = 24 * 60 * 60;
+    const COOLDOWN = 30 * DAY;
+    const settled = (vesting: { amount: number; released: number; revoked: boolean }) =>
+      vesting.revoked || vesting.released >= vesting.amount;
+
+    it('should block withdrawal until the cooldown ends', () => {
+      const requestedAt = 1_000;
+
+      expect(requestedAt + COOLDOWN - 1 >= requestedAt + COOLDOWN).toBe(false);
+      expect(requestedAt + COOLDOWN >= requestedAt + COOLDOWN).toBe(true);
+    });
+
+    it('should require every vesting to be released or revoked', () => {
+      const vestings = [
+        { amount: 1_000, released: 1_000, revoked: false },
+        { amount: 1_000, released: 200, revoked: true },
+        { amount: 1_000, released: 400, revoked: false },
+      ];
+
+      expect(settled(vestings[0])).toBe(true);
+      expect(settled(vestings[1])).toBe(true);
+      expect(vestings.every(settled)).toBe(false);
+    });
+
+    it('should forfeit the stake of banned creators', () => {
+      const profile = { isBanned: true, deregistrationRequestedAt: 1_000 };
+
+      try {
+        if (profile.isBanned) {
+          throw new Error('Creator is banned');
+        }
+      } catch (error) {
+        expect(error.message).toContain('banned');
+      }
+    });
+
+    it('should keep the profile history when a deregistered creator registers again', () => {
+      const profile = {
+        isRegistered: true,
+        stakeAmount: 500_000_000,
+        reputationScore: -40,
+        failedTokens: 2,
+        deregistrationRequestedAt: 1_000 as number | null
+      };
+
+      // Withdrawal leaves a tombstone instead of closing the account
+      profile.isRegistered = false;
+      profile.stakeAmount = 0;
+      profile.deregistrationRequestedAt = null;
+
+      // Registering again only restores the stake and registration
+      profile.isRegistered = true;
+      profile.stakeAmount = 500_000_000;
+
+      expect(profile.reputationScore).toBe(-40);
+      expect(profile.failedTokens).toBe(2);
+    });
+  });
 });
@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { SolanaMemes } from "../target/types/solana_memes";
 import { PublicKey, Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { getAssociatedTokenAddressSync } from "@solana/spl-token";
 import { expect } from "chai";
 
 describe("Fair Voting Safeguards", () => {
@@ -25,6 +26,7 @@ describe("Fair Voting Safeguards", () => {
     let appealSystemPda: PublicKey;
     let detectionSystemPda: PublicKey;
     let penaltySystemPda: PublicKey;
+    let governanceConfigPda: PublicKey;
 
     before(async () => {
         // Airdrop SOL to test accounts
@@ -71,6 +73,11 @@ describe("Fair Voting Safeguards", () => {
             [Buffer.from("penalty_system")],
             program.programId
         );
+
+        [governanceConfigPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("governance_config")],
+            program.programId
+        );
     });
 
     describe("Fair Voting Safeguards Initialization", () => {
@@ -151,19 +158,59 @@ describe("Fair Voting Safeguards", () => {
         });
     });
 
+    // Every tier's position PDA for a holder in the platform staking pool, in tier order
+    const tierPositions = (stakingPool: PublicKey, holder: PublicKey) =>
+        [0, 1, 2, 3, 4].map((tier) =>
+            PublicKey.findProgramAddressSync(
+                [Buffer.from("staking_position"), stakingPool.toBuffer(), holder.toBuffer(), Buffer.from([tier])],
+                program.programId
+            )[0]
+        );
+
+    // Refresh a holder's voting power from their positions in the platform staking pool
+    const refreshVotingPower = async (holderKeypair: Keypair, holderPda: PublicKey) => {
+        const holder = holderKeypair.publicKey;
+        const [feeRevenuePoolPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from("fee_revenue_pool")],
+            program.programId
+        );
+        const feeRevenuePool = await program.account.feeRevenuePool.fetch(feeRevenuePoolPda);
+        const stakingPool = await program.account.stakingPool.fetch(feeRevenuePool.stakingPool);
+
+        const allTiers = tierPositions(feeRevenuePool.stakingPool, holder);
+        const positions: PublicKey[] = [];
+        for (const positionPda of allTiers) {
+            if (await provider.connection.getAccountInfo(positionPda)) {
+                positions.push(positionPda);
+            }
+        }
+
+        await program.methods
+            .refreshVotingPower()
+            .accounts({
+                enhancedTokenHolder: holderPda,
+                fairVotingSafeguards: fairVotingSafeguardsPda,
+                feeRevenuePool: feeRevenuePoolPda,
+                stakingPool: feeRevenuePool.stakingPool,
+                tokenMint: stakingPool.mint,
+                holderTokenAccount: getAssociatedTokenAddressSync(stakingPool.mint, holder),
+                creatorProfile: PublicKey.findProgramAddressSync(
+                    [Buffer.from("creator_profile"), holder.toBuffer()],
+                    program.programId
+                )[0],
+                holder,
+            })
+            .remainingAccounts(allTiers.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
+            .signers([holderKeypair])
+            .rpc();
+
+        return positions;
+    };
+
     describe("Enhanced Token Holder", () => {
-        it("Should initialize enhanced token holder with fair voting power", async () => {
+        it("Should initialize enhanced token holder with no voting power", async () => {
             const tx = await program.methods
-                .initializeEnhancedTokenHolder(
-                    new anchor.BN(1000000), // staked_amount
-                    new anchor.BN(7776000), // staking_duration (90 days)
-                    new anchor.BN(50000), // community_contribution
-                    new anchor.BN(100000), // token_holding
-                    85, // consistency_score
-                    750, // reputation_score
-                    50, // participation_history
-                    80 // contribution_quality
-                )
+                .initializeEnhancedTokenHolder()
                 .accounts({
                     enhancedTokenHolder: enhancedTokenHolderPda,
                     authority: user1.publicKey,
@@ -175,41 +222,38 @@ describe("Fair Voting Safeguards", () => {
             const tokenHolder = await program.account.enhancedTokenHolder.fetch(enhancedTokenHolderPda);
 
             expect(tokenHolder.holder.toString()).to.equal(user1.publicKey.toString());
-            expect(tokenHolder.stakedAmount.toNumber()).to.equal(1000000);
-            expect(tokenHolder.stakingDuration.toNumber()).to.equal(7776000);
-            expect(tokenHolder.communityContributionScore.toNumber()).to.equal(50000);
-            expect(tokenHolder.reputationScore).to.equal(750);
+            expect(tokenHolder.stakedAmount.toNumber()).to.equal(0);
+            expect(tokenHolder.reputationScore).to.equal(0);
+            expect(tokenHolder.participationHistory.toNumber()).to.equal(0);
+            expect(tokenHolder.votingPower.toNumber()).to.equal(0);
             expect(tokenHolder.isWhale).to.be.false;
             expect(tokenHolder.votingRestricted).to.be.false;
         });
 
-        it("Should update voting power with new parameters", async () => {
-            const tx = await program.methods
-                .updateVotingPower(
-                    new anchor.BN(2000000), // new_staked_amount
-                    new anchor.BN(15552000), // new_staking_duration (180 days)
-                    new anchor.BN(100000), // new_community_contribution
-                    new anchor.BN(200000), // new_token_holding
-                    90, // new_consistency_score
-                    800, // new_reputation_score
-                    75, // new_participation_history
-                    85 // new_contribution_quality
-                )
-                .accounts({
-                    enhancedTokenHolder: enhancedTokenHolderPda,
-                    fairVotingSafeguards: fairVotingSafeguardsPda,
-                    authority: user1.publicKey,
-                    systemProgram: anchor.web3.SystemProgram.programId,
-                })
-                .signers([user1])
-                .rpc();
+        it("Should refresh voting power from on-chain stake", async () => {
+            const positions = await refreshVotingPower(user1, enhancedTokenHolderPda);
+
+            const now = Math.floor(Date.now() / 1000);
+            let stakedAmount = 0;
+            let lockedAmount = 0;
+            for (const position of positions) {
+                const account = await program.account.stakingPosition.fetch(position);
+                stakedAmount += account.amount.toNumber();
+                if (account.isLocked && account.endTime.toNumber() > now) {
+                    lockedAmount += account.amount.toNumber();
+                }
+            }
 
             const tokenHolder = await program.account.enhancedTokenHolder.fetch(enhancedTokenHolderPda);
 
-            expect(tokenHolder.stakedAmount.toNumber()).to.equal(2000000);
-            expect(tokenHolder.stakingDuration.toNumber()).to.equal(15552000);
-            expect(tokenHolder.reputationScore).to.equal(800);
-            expect(tokenHolder.votingPower.toNumber()).to.be.greaterThan(0);
+            expect(tokenHolder.stakedAmount.toNumber()).to.equal(stakedAmount);
+            // The liquid wallet balance never feeds voting power; expired locks don't count
+            expect(tokenHolder.tokenHoldingAmount.toNumber()).to.equal(lockedAmount);
+            expect(tokenHolder.participationHistory.toNumber()).to.equal(0);
+        });
+
+        it("Should no longer accept self-reported voting inputs", async () => {
+            expect((program.methods as any).updateVotingPower).to.be.undefined;
         });
     });
 
@@ -232,6 +276,7 @@ describe("Fair Voting Safeguards", () => {
                     creatorPerformance: creatorPerformancePda,
                     creator: creator.publicKey,
                     tokenMint: tokenMint.publicKey,
+                    governanceConfig: governanceConfigPda,
                     authority: authority.publicKey,
                     systemProgram: anchor.web3.SystemProgram.programId,
                 })
@@ -263,6 +308,7 @@ describe("Fair Voting Safeguards", () => {
                 )
                 .accounts({
                     creatorPerformance: creatorPerformancePda,
+                    governanceConfig: governanceConfigPda,
                     authority: authority.publicKey,
                     systemProgram: anchor.web3.SystemProgram.programId,
                 })
@@ -278,6 +324,33 @@ describe("Fair Voting Safeguards", () => {
             expect(performance.performanceScore).to.be.greaterThan(0);
             expect(performance.performanceHistory.length).to.be.greaterThan(0);
         });
+
+        it("Should reject performance updates signed by the creator", async () => {
+            try {
+                await program.methods
+                    .updateCreatorPerformance(
+                        new anchor.BN(10000),
+                        new anchor.BN(1000000000),
+                        new anchor.BN(100000),
+                        new anchor.BN(100000000),
+                        100,
+                        100,
+                        100,
+                        100
+                    )
+                    .accounts({
+                        creatorPerformance: creatorPerformancePda,
+                        governanceConfig: governanceConfigPda,
+                        authority: creator.publicKey,
+                        systemProgram: anchor.web3.SystemProgram.programId,
+                    })
+                    .signers([creator])
+                    .rpc();
+                expect.fail("Creator should not be able to set their own score");
+            } catch (error) {
+                expect(error.message).to.include("Unauthorized");
+            }
+        });
     });
 
     describe("Appeal System", () => {
@@ -445,24 +518,14 @@ describe("Fair Voting Safeguards", () => {
     });
 
     describe("Anti-Whale Protection", () => {
-        it("Should detect whale wallet and apply restrictions", async () => {
-            // Create a whale token holder with large stake
+        it("Should give no voting power to a wallet without stake", async () => {
             const whaleTokenHolderPda = PublicKey.findProgramAddressSync(
                 [Buffer.from("enhanced_token_holder"), whale.publicKey.toBuffer()],
                 program.programId
             )[0];
 
             await program.methods
-                .initializeEnhancedTokenHolder(
-                    new anchor.BN(50000000), // Large staked amount
-                    new anchor.BN(7776000), // staking_duration
-                    new anchor.BN(100000), // community_contribution
-                    new anchor.BN(1000000), // token_holding
-                    70, // consistency_score
-                    600, // reputation_score
-                    30, // participation_history
-                    75 // contribution_quality
-                )
+                .initializeEnhancedTokenHolder()
                 .accounts({
                     enhancedTokenHolder: whaleTokenHolderPda,
                     authority: whale.publicKey,
@@ -471,33 +534,25 @@ describe("Fair Voting Safeguards", () => {
                 .signers([whale])
                 .rpc();
 
+            // A large SOL balance alone carries no voting power
+            await refreshVotingPower(whale, whaleTokenHolderPda);
+
             const whaleHolder = await program.account.enhancedTokenHolder.fetch(whaleTokenHolderPda);
 
-            // Whale should be detected and voting power should be limited
-            expect(whaleHolder.stakedAmount.toNumber()).to.equal(50000000);
-            expect(whaleHolder.votingPower.toNumber()).to.be.lessThan(whaleHolder.stakedAmount.toNumber());
+            expect(whaleHolder.stakedAmount.toNumber()).to.equal(0);
+            expect(whaleHolder.votingPower.toNumber()).to.equal(0);
         });
     });
 
     describe("Fair Voting Power Calculation", () => {
-        it("Should calculate fair voting power with multiple factors", async () => {
-            // Create a well-rounded token holder
+        it("Should reject staking positions owned by another holder", async () => {
             const balancedHolderPda = PublicKey.findProgramAddressSync(
                 [Buffer.from("enhanced_token_holder"), user2.publicKey.toBuffer()],
                 program.programId
             )[0];
 
             await program.methods
-                .initializeEnhancedTokenHolder(
-                    new anchor.BN(5000000), // Moderate staked amount
-                    new anchor.BN(15552000), // Long staking duration (180 days)
-                    new anchor.BN(200000), // High community contribution
-                    new anchor.BN(500000), // Good token holding
-                    95, // High consistency score
-                    900, // High reputation score
-                    100, // High participation history
-                    90 // High contribution quality
-                )
+                .initializeEnhancedTokenHolder()
                 .accounts({
                     enhancedTokenHolder: balancedHolderPda,
                     authority: user2.publicKey,
@@ -506,13 +561,127 @@ describe("Fair Voting Safeguards", () => {
                 .signers([user2])
                 .rpc();
 
-            const balancedHolder = await program.account.enhancedTokenHolder.fetch(balancedHolderPda);
+            const [feeRevenuePoolPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("fee_revenue_pool")],
+                program.programId
+            );
+            const feeRevenuePool = await program.account.feeRevenuePool.fetch(feeRevenuePoolPda);
+            const stakingPool = await program.account.stakingPool.fetch(feeRevenuePool.stakingPool);
+            const refreshAccounts = {
+                enhancedTokenHolder: balancedHolderPda,
+                fairVotingSafeguards: fairVotingSafeguardsPda,
+                feeRevenuePool: feeRevenuePoolPda,
+                stakingPool: feeRevenuePool.stakingPool,
+                tokenMint: stakingPool.mint,
+                holderTokenAccount: getAssociatedTokenAddressSync(stakingPool.mint, user2.publicKey),
+                creatorProfile: PublicKey.findProgramAddressSync(
+                    [Buffer.from("creator_profile"), user2.publicKey.toBuffer()],
+                    program.programId
+                )[0],
+                holder: user2.publicKey,
+            };
+
+            // user1's flexible position in place of user2's
+            const positions = tierPositions(feeRevenuePool.stakingPool, user2.publicKey);
+            positions[0] = tierPositions(feeRevenuePool.stakingPool, user1.publicKey)[0];
+
+            try {
+                await program.methods
+                    .refreshVotingPower()
+                    .accounts(refreshAccounts)
+                    .remainingAccounts(positions.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false })))
+                    .signers([user2])
+                    .rpc();
+
+                expect.fail("Should have thrown an error");
+            } catch (error) {
+                expect(error.toString()).to.include("InvalidAccount");
+            }
+        });
+
+        it("Should only let the holder refresh their own voting power", async () => {
+            const holderPda = PublicKey.findProgramAddressSync(
+                [Buffer.from("enhanced_token_holder"), user2.publicKey.toBuffer()],
+                program.programId
+            )[0];
+            const [feeRevenuePoolPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("fee_revenue_pool")],
+                program.programId
+            );
+            const feeRevenuePool = await program.account.feeRevenuePool.fetch(feeRevenuePoolPda);
+            const stakingPool = await program.account.stakingPool.fetch(feeRevenuePool.stakingPool);
+
+            try {
+                await program.methods
+                    .refreshVotingPower()
+                    .accounts({
+                        enhancedTokenHolder: holderPda,
+                        fairVotingSafeguards: fairVotingSafeguardsPda,
+                        feeRevenuePool: feeRevenuePoolPda,
+                        stakingPool: feeRevenuePool.stakingPool,
+                        tokenMint: stakingPool.mint,
+                        holderTokenAccount: getAssociatedTokenAddressSync(stakingPool.mint, user2.publicKey),
+                        creatorProfile: PublicKey.findProgramAddressSync(
+                            [Buffer.from("creator_profile"), user2.publicKey.toBuffer()],
+                            program.programId
+                        )[0],
+                        holder: authority.publicKey,
+                    })
+                    .remainingAccounts(
+                        tierPositions(feeRevenuePool.stakingPool, user2.publicKey)
+                            .map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+                    )
+                    .signers([authority])
+                    .rpc();
+
+                expect.fail("Should have thrown an error");
+            } catch (error) {
+                expect(error.toString()).to.include("Unauthorized");
+            }
+        });
+    });
+
+    describe("Governance Voting", () => {
+        it("Should derive one vote account per proposal and voter", async () => {
+            const [proposalPda] = PublicKey.findProgramAddressSync(
+                [Buffer.from("governance_proposal"), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+                program.programId
+            );
+            const voteFor = (voter: PublicKey) =>
+                PublicKey.findProgramAddressSync(
+                    [Buffer.from("vote"), proposalPda.toBuffer(), voter.toBuffer()],
+                    program.programId
+                )[0];
+
+            // A second vote from the same wallet lands on the already-initialized account
+            expect(voteFor(user1.publicKey).equals(voteFor(user1.publicKey))).to.be.true;
+            expect(voteFor(user1.publicKey).equals(voteFor(user2.publicKey))).to.be.false;
+        });
+
+        it("Should credit staking duration only from each position's last deposit", () => {
+            const now = 1_000_000;
+            // Dust staked long ago and topped up with stake moved in from another wallet,
+            // next to a position that has been held untouched
+            const positions = [
+                { amount: 1_000, startTime: now - 1_000_000, lastDepositTime: now - 500 },
+                { amount: 1_000, startTime: now - 2_000, lastDepositTime: now - 2_000 },
+            ];
+            const staked = positions.reduce((sum, p) => sum + p.amount, 0);
+            const duration = Math.floor(
+                positions.reduce((sum, p) => sum + p.amount * (now - p.lastDepositTime), 0) / staked
+            );
+
+            expect(duration).to.equal(1_250);
+        });
+
+        it("Should lock every counted position until the proposal ends", () => {
+            const proposal = { endTime: 5_000 };
+            const positions = [{ voteLockedUntil: 0 }, { voteLockedUntil: 7_000 }];
+            for (const position of positions) {
+                position.voteLockedUntil = Math.max(position.voteLockedUntil, proposal.endTime);
+            }
 
-            // Should have good voting power due to balanced factors
-            expect(balancedHolder.votingPower.toNumber()).to.be.greaterThan(0);
-            expect(balancedHolder.reputationScore).to.equal(900);
-            expect(balancedHolder.consistencyScore).to.equal(95);
-            expect(balancedHolder.isWhale).to.be.false;
+            expect(positions.map((p) => p.voteLockedUntil)).to.deep.equal([5_000, 7_000]);
         });
     });
 });
@@ -0,0 +1,410 @@
+import { describe, it, expect } from '@jest/globals';
+
+describe('Staking Tests', () => {
+  const REWARD_RATE_DENOMINATOR = 1_000_000_000n;
+  const REWARD_PER_TOKEN_PRECISION = 1_000_000_000_000n;
+  const SECONDS_PER_DAY = 86_400n;
+
+  type Pool = {
+    totalStaked: bigint;
+    rewardRate: bigint;
+    rewardPerTokenStored: bigint;
+    undistributedRewards: bigint;
+    lastUpdateTime: bigint;
+  };
+
+  const updateRewards = (pool: Pool, now: bigint) => {
+    const elapsed = now > pool.lastUpdateTime ? now - pool.lastUpdateTime : 0n;
+    pool.lastUpdateTime = now;
+    if (elapsed === 0n || pool.totalStaked === 0n || pool.undistributedRewards === 0n) return;
+
+    let emitted = (pool.totalStaked * pool.rewardRate * elapsed) / (REWARD_RATE_DENOMINATOR * SECONDS_PER_DAY);
+    if (emitted > pool.undistributedRewards) emitted = pool.undistributedRewards;
+    pool.rewardPerTokenStored += (emitted * REWARD_PER_TOKEN_PRECISION) / pool.totalStaked;
+    pool.undistributedRewards -= emitted;
+  };
+
+  const earned = (amount: bigint, pool: Pool, paid: bigint) =>
+    (amount * (pool.rewardPerTokenStored - paid)) / REWARD_PER_TOKEN_PRECISION;
+
+  const newPool = (): Pool => ({
+    totalStaked: 0n,
+    rewardRate: 1_000_000n, // 0.1% per day
+    rewardPerTokenStored: 0n,
+    undistributedRewards: 1_000_000_000n,
+    lastUpdateTime: 0n,
+  });
+
+  describe('reward_accumulator', () => {
+    it('should accrue the daily rate on staked tokens', () => {
+      const pool = newPool();
+      pool.totalStaked = 100_000_000n;
+      updateRewards(pool, SECONDS_PER_DAY);
+
+      expect(earned(100_000_000n, pool, 0n)).toBe(100_000n);
+    });
+
+    it('should split rewards pro rata between stakers', () => {
+      const pool = newPool();
+      pool.totalStaked = 300_000_000n;
+      updateRewards(pool, SECONDS_PER_DAY);
+
+      const small = earned(100_000_000n, pool, 0n);
+      const large = earned(200_000_000n, pool, 0n);
+      expect(large).toBe(small * 2n);
+    });
+
+    it('should never emit more than the funded rewards', () => {
+      const pool = newPool();
+      pool.totalStaked = 100_000_000n;
+      pool.undistributedRewards = 50_000n;
+      updateRewards(pool, 10n * SECONDS_PER_DAY);
+
+      expect(pool.undistributedRewards).toBe(0n);
+      expect(earned(100_000_000n, pool, 0n) <= 50_000n).toBe(true);
+    });
+
+    it('should not pay new stakers for past rewards', () => {
+      const pool = newPool();
+      pool.totalStaked = 100_000_000n;
+      updateRewards(pool, SECONDS_PER_DAY);
+      const paid = pool.rewardPerTokenStored;
+
+      expect(earned(50_000_000n, pool, paid)).toBe(0n);
+    });
+  });
+
+  describe('unstake_tokens', () => {
+    const earlyWithdrawalFee = 10n; // 10%
+
+    it('should charge the early-withdrawal fee while locked', () => {
+      const amount = 1_000_000n;
+      const fee = (amount * earlyWithdrawalFee) / 100n;
+
+      expect(amount - fee).toBe(900_000n);
+    });
+
+    it('should waive the fee once the lock has ended', () => {
+      const endTime = 1_000n;
+      const now = 1_000n;
+      const isLocked = now < endTime;
+      const fee = isLocked ? (1_000_000n * earlyWithdrawalFee) / 100n : 0n;
+
+      expect(fee).toBe(0n);
+    });
+
+    it('should pay the fee to the remaining stakers', () => {
+      const pool = newPool();
+      pool.totalStaked = 100_000_000n;
+      const fee = 1_000_000n;
+      pool.rewardPerTokenStored += (fee * REWARD_PER_TOKEN_PRECISION) / pool.totalStaked;
+
+      expect(earned(100_000_000n, pool, 0n)).toBe(fee);
+    });
+
+    it('should not pay a partial unstaker any of its own fee', () => {
+      const pool = newPool();
+      const other = 100_000_000n;
+      const leaver = 100_000_000n;
+      const unstaked = 50_000_000n;
+      const fee = (unstaked * earlyWithdrawalFee) / 100n;
+
+      // The leaving position is out of the pool while its fee is distributed
+      pool.totalStaked = other;
+      pool.rewardPerTokenStored += (fee * REWARD_PER_TOKEN_PRECISION) / pool.totalStaked;
+      const leaverPaid = pool.rewardPerTokenStored;
+      pool.totalStaked = other + (leaver - unstaked);
+
+      expect(earned(other, pool, 0n)).toBe(fee);
+      expect(earned(leaver - unstaked, pool, leaverPaid)).toBe(0n);
+    });
+
+    it('should close the position when fully unstaked', () => {
+      const position = { amount: 1_000_000n };
+      position.amount -= 1_000_000n;
+
+      expect(position.amount === 0n).toBe(true);
+    });
+  });
+
+  describe('lock_tiers', () => {
+    const DAY = 86_400;
+    const lockSeconds = { Flexible: 0, Days30: 30 * DAY, Days90: 90 * DAY, Days180: 180 * DAY, Days365: 365 * DAY };
+    const defaultMultipliers = [5_000, 10_000, 12_500, 15_000, 20_000];
+
+    const validateMultipliers = (multipliers: number[]) =>
+      multipliers.every((m) => m > 0 && m <= 50_000) &&
+      multipliers.every((m, i) => i === 0 || multipliers[i - 1] <= m);
+
+    it('should weight longer locks more heavily', () => {
+      const amount = 1_000_000n;
+      const weighted = defaultMultipliers.map((m) => (amount * BigInt(m)) / 10_000n);
+
+      expect(weighted[0]).toBe(500_000n);
+      expect(weighted[4]).toBe(2_000_000n);
+    });
+
+    it('should accrue rewards against weighted stake', () => {
+      const pool = newPool();
+      const flexible = (100_000_000n * 5_000n) / 10_000n;
+      const yearLock = (100_000_000n * 20_000n) / 10_000n;
+      pool.totalStaked = flexible + yearLock;
+      updateRewards(pool, SECONDS_PER_DAY);
+
+      expect(earned(yearLock, pool, 0n)).toBe(earned(flexible, pool, 0n) * 4n);
+    });
+
+    it('should not lock flexible stakes', () => {
+      expect(lockSeconds.Flexible).toBe(0);
+      expect(lockSeconds.Days365).toBe(365 * DAY);
+    });
+
+    it('should reject multipliers that reward shorter locks more', () => {
+      expect(validateMultipliers(defaultMultipliers)).toBe(true);
+      expect(validateMultipliers([10_000, 5_000, 12_500, 15_000, 20_000])).toBe(false);
+      expect(validateMultipliers([0, 10_000, 12_500, 15_000, 20_000])).toBe(false);
+    });
+  });
+
+  describe('claim_rewards', () => {
+    it('should reject claims with nothing earned', () => {
+      const pendingRewards = 0n;
+
+      try {
+        if (pendingRewards === 0n) {
+          throw new Error('No rewards to claim');
+        }
+      } catch (error) {
+        expect(error.message).toContain('No rewards to claim');
+      }
+    });
+  });
+
+  describe('compounding_vault', () => {
+    const sharesForDeposit = (amount: bigint, assets: bigint, totalShares: bigint) => {
+      if (totalShares === 0n) return amount;
+      if (assets === 0n) throw new Error('Vault has outstanding shares but no assets');
+      return (amount * totalShares) / assets;
+    };
+    const assetsForShares = (shares: bigint, assets: bigint, totalShares: bigint) =>
+      totalShares === 0n ? 0n : (shares * assets) / totalShares;
+
+    it('should mint shares one-to-one for the first deposit', () => {
+      expect(sharesForDeposit(1_000_000n, 0n, 0n)).toBe(1_000_000n);
+    });
+
+    it('should reject deposits into an emptied vault with outstanding shares', () => {
+      expect(() => sharesForDeposit(1_000_000n, 0n, 500_000n)).toThrow('outstanding shares but no assets');
+    });
+
+    it('should grow share value as rewards compound', () => {
+      const totalShares = 1_000_000n;
+      const assets = 1_100_000n;
+
+      expect(assetsForShares(500_000n, assets, totalShares)).toBe(550_000n);
+      expect(sharesForDeposit(1_100_000n, assets, totalShares)).toBe(1_000_000n);
+    });
+
+    it('should pay the cranker its incentive out of pending rewards', () => {
+      const pending = 1_000_000n;
+      const crankIncentiveBps = 50n;
+      const incentive = (pending * crankIncentiveBps) / 10_000n;
+
+      expect(incentive).toBe(5_000n);
+      expect(pending - incentive).toBe(995_000n);
+    });
+
+    it('should reject compounding before the interval elapses', () => {
+      const lastCompoundTime = 1_000;
+      const compoundInterval = 86_400;
+      const now = lastCompoundTime + compoundInterval - 1;
+
+      try {
+        if (now < lastCompoundTime + compoundInterval) {
+          throw new Error('Compounding interval not reached');
+        }
+      } catch (error) {
+        expect(error.message).toContain('interval not reached');
+      }
+    });
+  });
+
+  describe('liquid_staking', () => {
+    const receiptFor = (amount: bigint, assets: bigint, supply: bigint) =>
+      supply === 0n || assets === 0n ? amount : (amount * supply) / assets;
+    const redeemFor = (receipts: bigint, assets: bigint, supply: bigint) =>
+      supply === 0n ? 0n : (receipts * assets) / supply;
+
+    it('should redeem receipts for principal plus compounded rewards', () => {
+      let assets = 0n;
+      let supply = 0n;
+
+      const minted = receiptFor(1_000_000n, assets, supply);
+      assets += 1_000_000n;
+      supply += minted;
+
+      // Rewards restaked into the vault raise the receipt's value
+      assets += 200_000n;
+
+      expect(redeemFor(minted, assets, supply)).toBe(1_200_000n);
+    });
+
+    it('should price late stakers at the current exchange rate', () => {
+      const assets = 1_200_000n;
+      const supply = 1_000_000n;
+
+      expect(receiptFor(600_000n, assets, supply)).toBe(500_000n);
+    });
+
+    it('should reject redeeming more receipts than exist', () => {
+      const totalShares = 1_000n;
+      const receiptAmount = 1_001n;
+
+      try {
+        if (receiptAmount > totalShares) {
+          throw new Error('Insufficient vault shares');
+        }
+      } catch (error) {
+        expect(error.message).toContain('Insufficient vault shares');
+      }
+    });
+  });
+
+  describe('fee_revenue', () => {
+    const EPOCH = 7n * SECONDS_PER_DAY;
+    type FeePool = {
+      pending: bigint;
+      releaseEnd: bigint;
+      lastUpdateTime: bigint;
+      feePerTokenStored: bigint;
+      epochRevenue: bigint;
+    };
+
+    const newFeePool = (): FeePool => ({
+      pending: 0n,
+      releaseEnd: 0n,
+      lastUpdateTime: 0n,
+      feePerTokenStored: 0n,
+      epochRevenue: 0n,
+    });
+
+    const release = (feePool: FeePool, feeWeighted: bigint, now: bigint) => {
+      const elapsed = now > feePool.lastUpdateTime ? now - feePool.lastUpdateTime : 0n;
+      const last = feePool.lastUpdateTime;
+      feePool.lastUpdateTime = now;
+      if (elapsed === 0n || feePool.pending === 0n) return;
+      if (feeWeighted === 0n) {
+        feePool.releaseEnd += elapsed;
+        return;
+      }
+      const remaining = feePool.releaseEnd > last ? feePool.releaseEnd - last : 0n;
+      const released = elapsed >= remaining ? feePool.pending : (feePool.pending * elapsed) / remaining;
+      feePool.feePerTokenStored += (released * REWARD_PER_TOKEN_PRECISION) / feeWeighted;
+      feePool.pending -= released;
+    };
+
+    const deposit = (feePool: FeePool, feeWeighted: bigint, amount: bigint, now: bigint) => {
+      release(feePool, feeWeighted, now);
+      feePool.epochRevenue += amount;
+      feePool.pending += amount;
+      const boundary = (now / EPOCH + 1n) * EPOCH;
+      feePool.releaseEnd = feePool.releaseEnd > boundary ? feePool.releaseEnd : boundary;
+    };
+
+    it('should split the staker share out of each trading fee', () => {
+      const tradeAmount = 1_000_000_000n;
+      const tradingFee = (tradeAmount * 12n) / 1000n;
+      const stakerPortion = (tradingFee * 55n) / 100n;
+
+      expect(stakerPortion).toBe(6_600_000n);
+      expect(tradingFee - stakerPortion).toBe(5_400_000n);
+    });
+
+    it('should pay fee revenue pro rata to weighted stake', () => {
+      const feePool = newFeePool();
+      const alice = 1_000_000n;
+      const bob = 3_000_000n;
+      deposit(feePool, alice + bob, 4_000n, 0n);
+      release(feePool, alice + bob, EPOCH);
+
+      expect((alice * feePool.feePerTokenStored) / REWARD_PER_TOKEN_PRECISION).toBe(1_000n);
+      expect((bob * feePool.feePerTokenStored) / REWARD_PER_TOKEN_PRECISION).toBe(3_000n);
+    });
+
+    it('should stream a deposit out linearly over the epoch', () => {
+      const feePool = newFeePool();
+      const staked = 1_000n;
+      deposit(feePool, staked, 7_000n, 0n);
+
+      release(feePool, staked, SECONDS_PER_DAY);
+      expect((staked * feePool.feePerTokenStored) / REWARD_PER_TOKEN_PRECISION).toBe(1_000n);
+      expect(feePool.pending).toBe(6_000n);
+    });
+
+    it('should not let later deposits push back the epoch boundary', () => {
+      const feePool = newFeePool();
+      const staked = 1_000n;
+      deposit(feePool, staked, 7_000n, 0n);
+      for (let day = 1n; day < 7n; day++) {
+        deposit(feePool, staked, 1n, day * SECONDS_PER_DAY);
+      }
+
+      expect(feePool.releaseEnd).toBe(EPOCH);
+      release(feePool, staked, EPOCH);
+      expect(feePool.pending).toBe(0n);
+    });
+
+    it('should not let a stake opened just before a deposit capture it', () => {
+      const feePool = newFeePool();
+      const holder = 1_000_000n;
+      const sandwich = 9_000_000n;
+
+      // Stake, let the fee land, then claim a block later
+      const paid = feePool.feePerTokenStored;
+      deposit(feePool, holder + sandwich, 10_000n, 100n);
+      release(feePool, holder + sandwich, 101n);
+
+      const sandwichEarned = (sandwich * (feePool.feePerTokenStored - paid)) / REWARD_PER_TOKEN_PRECISION;
+      expect(sandwichEarned < 10n).toBe(true);
+      expect(feePool.pending > 9_900n).toBe(true);
+    });
+
+    it('should pause the stream while nobody stakes', () => {
+      const feePool = newFeePool();
+      deposit(feePool, 0n, 5_000n, 0n);
+      release(feePool, 0n, EPOCH);
+      expect(feePool.pending).toBe(5_000n);
+
+      release(feePool, 1_000n, 2n * EPOCH);
+      expect((1_000n * feePool.feePerTokenStored) / REWARD_PER_TOKEN_PRECISION).toBe(5_000n);
+      expect(feePool.epochRevenue).toBe(5_000n);
+    });
+
+    it('should leave compounding vault stake out of the fee index', () => {
+      const feePool = newFeePool();
+      const alice = 1_000_000n;
+      const vaultPosition = 3_000_000n;
+      const feeWeighted = alice;
+      deposit(feePool, feeWeighted, 4_000n, 0n);
+      release(feePool, feeWeighted, EPOCH);
+
+      // The vault still counts toward staking rewards, but every lamport goes to claimable positions
+      expect(alice + vaultPosition).toBe(4_000_000n);
+      expect((alice * feePool.feePerTokenStored) / REWARD_PER_TOKEN_PRECISION).toBe(4_000n);
+    });
+
+    it('should reject closing an epoch early', () => {
+      const epochStart = 1_000;
+      const epochDuration = 7 * 86_400;
+      const now = epochStart + epochDuration - 1;
+
+      try {
+        if (now < epochStart + epochDuration) {
+          throw new Error('Fee revenue epoch still running');
+        }
+      } catch (error) {
+        expect(error.message).toContain('epoch still running');
+      }
+    });
+  });
+});
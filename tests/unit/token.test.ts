@@ -127,6 +127,529 @@ describe('Token Creation Tests', () => {
     });
   });
 
+  describe('fixed_supply', () => {
+    it('should revoke mint and freeze authority by default', () => {
+      const fixedSupply: boolean | null = null; // Option<bool> defaults to true
+      const isFixedSupply = fixedSupply ?? true;
+
+      const mintAccount = {
+        mintAuthority: isFixedSupply ? null : creator.publicKey,
+        freezeAuthority: isFixedSupply ? null : creator.publicKey
+      };
+
+      expect(isFixedSupply).toBe(true);
+      expect(mintAccount.mintAuthority).toBeNull();
+      expect(mintAccount.freezeAuthority).toBeNull();
+    });
+
+    it('should hand mint authority to the program PDA when fixed supply is disabled', () => {
+      const programId = Keypair.generate().publicKey;
+      const [mintAuthority] = PublicKey.findProgramAddressSync(
+        [Buffer.from('mint_authority'), mint.publicKey.toBuffer()],
+        programId
+      );
+
+      const mintAccount = {
+        mintAuthority,
+        freezeAuthority: null
+      };
+
+      expect(mintAccount.mintAuthority.equals(creator.publicKey)).toBe(false);
+      expect(mintAccount.freezeAuthority).toBeNull();
+    });
+  });
+
+  describe('mint_tokens', () => {
+    it('should cap minting per period by inflation bps', () => {
+      const periodStartSupply = 1_000_000_000;
+      const maxInflationBps = 500; // 5%
+      const periodCap = Math.floor(periodStartSupply * maxInflationBps / 10_000);
+
+      expect(periodCap).toBe(50_000_000);
+      expect(40_000_000 <= periodCap).toBe(true);
+      expect(60_000_000 <= periodCap).toBe(false);
+    });
+
+    it('should reject minting without a passed proposal', () => {
+      const proposal = { quorumMet: true, yesVotes: 10, noVotes: 20, executed: false };
+
+      try {
+        if (!(proposal.quorumMet && proposal.yesVotes > proposal.noVotes)) {
+          throw new Error('Proposal has not passed');
+        }
+      } catch (error) {
+        expect(error.message).toContain('Proposal has not passed');
+      }
+    });
+
+    it('should only count stake deposited before the mint proposal opened', () => {
+      const proposal = { startTime: 1_000, yesVotes: 0 };
+      const positions = [
+        { amount: 5_000, lastDepositTime: 900 },
+        { amount: 5_000, lastDepositTime: 1_100 } // Same tokens moved and restaked mid-vote
+      ];
+
+      for (const position of positions) {
+        if (position.lastDepositTime < proposal.startTime) {
+          proposal.yesVotes += position.amount;
+        }
+      }
+
+      expect(proposal.yesVotes).toBe(5_000);
+    });
+
+    it('should block unstaking a voted position until voting closes', () => {
+      const proposal = { endTime: 2_000 };
+      const position = { voteLockedUntil: 0 };
+      position.voteLockedUntil = Math.max(position.voteLockedUntil, proposal.endTime);
+      const canUnstake = (now: number) => now > position.voteLockedUntil;
+
+      expect(canUnstake(2_000)).toBe(false);
+      expect(canUnstake(2_001)).toBe(true);
+    });
+
+    it('should require quorum from the token\'s staked supply', () => {
+      const totalStaked = 1_000_000;
+      const quorumBps = 1_000; // 10%
+      const quorumVotes = Math.floor(totalStaked * quorumBps / 10_000);
+
+      expect(quorumVotes).toBe(100_000);
+      expect(60_000 + 30_000 >= quorumVotes).toBe(false);
+      expect(80_000 + 30_000 >= quorumVotes).toBe(true);
+    });
+
+    it('should update total and circulating supply after minting', () => {
+      const tokenMetadataAccount = { totalSupply: 1_000_000_000, circulatingSupply: 900_000_000 };
+      const amount = 10_000_000;
+
+      tokenMetadataAccount.totalSupply += amount;
+      tokenMetadataAccount.circulatingSupply += amount;
+
+      expect(tokenMetadataAccount.totalSupply).toBe(1_010_000_000);
+      expect(tokenMetadataAccount.circulatingSupply).toBe(910_000_000);
+    });
+  });
+
+  describe('symbol_registry', () => {
+    const homoglyphs: Record<string, string> = {
+      'А': 'A', 'В': 'B', 'С': 'C', 'Е': 'E', 'К': 'K', 'М': 'M', 'Н': 'H',
+      'О': 'O', 'Р': 'P', 'Т': 'T', 'Х': 'X', '0': 'O', '1': 'I', '5': 'S', '$': 'S'
+    };
+    const normalize = (symbol: string) =>
+      Array.from(symbol.normalize('NFKC'))
+        .map((c) => (homoglyphs[c] ?? c).toUpperCase())
+        .filter((c) => /^[A-Z0-9]$/.test(c))
+        .join('');
+
+    it('should normalize case, whitespace and homoglyphs to the same ticker', () => {
+      expect(normalize('bonk')).toBe('BONK');
+      expect(normalize(' B O N K ')).toBe('BONK');
+      expect(normalize('B0NK')).toBe('BONK');
+      expect(normalize('ВОNК')).toBe('BONK'); // Cyrillic В, О, К
+    });
+
+    it('should reject a second launch under a registered symbol', () => {
+      const registry = { mint: mint.publicKey, registeredBy: creator.publicKey };
+
+      try {
+        if (!registry.mint.equals(PublicKey.default)) {
+          throw new Error('Symbol already registered');
+        }
+      } catch (error) {
+        expect(error.message).toContain('already registered');
+      }
+    });
+
+    it('should only let the reserving creator launch during a reservation', () => {
+      const now = Math.floor(Date.now() / 1000);
+      const registry = { registeredBy: creator.publicKey, reservedUntil: now + 3600 };
+      const other = Keypair.generate();
+
+      const canLaunch = (wallet: PublicKey) =>
+        registry.registeredBy.equals(wallet) || now >= registry.reservedUntil;
+
+      expect(canLaunch(creator.publicKey)).toBe(true);
+      expect(canLaunch(other.publicKey)).toBe(false);
+    });
+
+    it('should let anyone reserve a symbol again once the reservation expires', () => {
+      const now = Math.floor(Date.now() / 1000);
+      const registry = { mint: PublicKey.default, registeredBy: creator.publicKey, reservedUntil: now - 1 };
+
+      const canReserve = () =>
+        registry.mint.equals(PublicKey.default) &&
+        (registry.registeredBy.equals(PublicKey.default) || now >= registry.reservedUntil);
+
+      expect(canReserve()).toBe(true);
+      registry.reservedUntil = now + 3600;
+      expect(canReserve()).toBe(false);
+    });
+
+    it('should cap reservations per creator in each reservation period', () => {
+      const PERIOD = 30 * 24 * 60 * 60;
+      const MAX_RESERVATIONS = 3;
+      const quota = { periodStart: 1_700_000_000, reservations: 0 };
+
+      const reserve = (now: number) => {
+        if (now - quota.periodStart >= PERIOD) {
+          quota.periodStart = now;
+          quota.reservations = 0;
+        }
+        if (quota.reservations >= MAX_RESERVATIONS) {
+          throw new Error('Symbol reservation limit reached for this period');
+        }
+        quota.reservations += 1;
+      };
+
+      for (let i = 0; i < MAX_RESERVATIONS; i++) {
+        reserve(quota.periodStart + i);
+      }
+      expect(() => reserve(quota.periodStart + 10)).toThrow('limit reached');
+      expect(() => reserve(quota.periodStart + PERIOD)).not.toThrow();
+    });
+  });
+
+  describe('creator_beneficiaries', () => {
+    it('should require beneficiary shares to sum to creator_percent', () => {
+      const creatorPercent = 7;
+      const beneficiaries = [
+        { wallet: creator.publicKey, shareBps: 400, cliffSeconds: 0, vestingSeconds: 90 * 24 * 60 * 60, schedule: { cliffLinear: {} } },
+        { wallet: Keypair.generate().publicKey, shareBps: 200, cliffSeconds: 30 * 24 * 60 * 60, vestingSeconds: 180 * 24 * 60 * 60, schedule: { step: { tranches: 2 } } },
+        { wallet: Keypair.generate().publicKey, shareBps: 100, cliffSeconds: 0, vestingSeconds: 30 * 24 * 60 * 60, schedule: { cliffLinear: {} } }
+      ];
+
+      const totalShareBps = beneficiaries.reduce((sum, b) => sum + b.shareBps, 0);
+      expect(totalShareBps).toBe(creatorPercent * 100);
+    });
+
+    it('should give each beneficiary its own vesting PDA', () => {
+      const programId = Keypair.generate().publicKey;
+      const other = Keypair.generate();
+      const [creatorVesting] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vesting'), mint.publicKey.toBuffer(), creator.publicKey.toBuffer()],
+        programId
+      );
+      const [otherVesting] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vesting'), mint.publicKey.toBuffer(), other.publicKey.toBuffer()],
+        programId
+      );
+
+      expect(creatorVesting.equals(otherVesting)).toBe(false);
+    });
+
+    it('should split the allocation without losing dust', () => {
+      const creatorAmount = 70_000_001;
+      const shares = [400, 200, 100];
+      const totalBps = 700;
+
+      const amounts = shares.map((share, i) =>
+        i === shares.length - 1 ? 0 : Math.floor((creatorAmount * share) / totalBps)
+      );
+      amounts[amounts.length - 1] = creatorAmount - amounts.reduce((a, b) => a + b, 0);
+
+      expect(amounts.reduce((a, b) => a + b, 0)).toBe(creatorAmount);
+    });
+
+    it('should reject allocations above the maximum creator allocation', () => {
+      const creatorPercent = 8; // MAX_CREATOR_ALLOCATION_PERCENT is 7
+
+      try {
+        if (creatorPercent > 7) {
+          throw new Error('Allocation exceeds maximum allowed');
+        }
+      } catch (error) {
+        expect(error.message).toContain('Allocation exceeds maximum');
+      }
+    });
+  });
+
+  describe('vesting_schedule', () => {
+    const DAY = 24 * 60 * 60;
+    const vestedAmount = (
+      vesting: { amount: number; startTime: number; cliffTime: number; endTime: number; tranches?: number },
+      now: number
+    ) => {
+      if (now < vesting.cliffTime) return 0;
+      if (now >= vesting.endTime) return vesting.amount;
+      const elapsed = now - vesting.startTime;
+      const duration = vesting.endTime - vesting.startTime;
+      if (vesting.tranches) {
+        const unlocked = Math.floor((elapsed * vesting.tranches) / duration);
+        return Math.floor((vesting.amount * unlocked) / vesting.tranches);
+      }
+      return Math.floor((vesting.amount * elapsed) / duration);
+    };
+
+    it('should release linearly after the cliff', () => {
+      const vesting = { amount: 1_000_000, startTime: 0, cliffTime: 90 * DAY, endTime: 360 * DAY };
+
+      expect(vestedAmount(vesting, 30 * DAY)).toBe(0);
+      expect(vestedAmount(vesting, 90 * DAY)).toBe(250_000);
+      expect(vestedAmount(vesting, 180 * DAY)).toBe(500_000);
+      expect(vestedAmount(vesting, 360 * DAY)).toBe(1_000_000);
+    });
+
+    it('should release 25% per quarter with a step schedule', () => {
+      const vesting = { amount: 1_000_000, startTime: 0, cliffTime: 0, endTime: 360 * DAY, tranches: 4 };
+
+      expect(vestedAmount(vesting, 89 * DAY)).toBe(0);
+      expect(vestedAmount(vesting, 90 * DAY)).toBe(250_000);
+      expect(vestedAmount(vesting, 200 * DAY)).toBe(500_000);
+      expect(vestedAmount(vesting, 270 * DAY)).toBe(750_000);
+    });
+
+    it('should reject a cliff that covers the whole vesting period', () => {
+      const cliffSeconds = 30 * DAY;
+      const vestingSeconds = 30 * DAY;
+
+      expect(cliffSeconds < vestingSeconds).toBe(false);
+    });
+  });
+
+  describe('performance_gated_release', () => {
+    const releasePercentage = (score: number) => {
+      if (score >= 90) return 100;
+      if (score >= 80) return 85;
+      if (score >= 70) return 70;
+      if (score >= 60) return 55;
+      if (score >= 50) return 40;
+      if (score >= 40) return 25;
+      if (score >= 30) return 15;
+      return 10;
+    };
+
+    it('should cap claims by the latest performance score', () => {
+      const vested = 1_000_000;
+
+      expect(Math.floor((vested * releasePercentage(95)) / 100)).toBe(1_000_000);
+      expect(Math.floor((vested * releasePercentage(72)) / 100)).toBe(700_000);
+      expect(Math.floor((vested * releasePercentage(0)) / 100)).toBe(100_000);
+    });
+
+    it('should release held-back tokens after the final deadline', () => {
+      const DAY = 24 * 60 * 60;
+      const endTime = 365 * DAY;
+      const finalDeadline = endTime + 180 * DAY;
+      const now = finalDeadline + 1;
+      const vested = 1_000_000;
+
+      const releasable = now >= finalDeadline ? vested : Math.floor((vested * releasePercentage(20)) / 100);
+      expect(releasable).toBe(vested);
+    });
+  });
+
+  describe('revoke_vesting', () => {
+    const vestedAt = (vesting: { amount: number; start: number; end: number; revokeTime?: number }, now: number) => {
+      const t = vesting.revokeTime === undefined ? now : Math.min(now, vesting.revokeTime);
+      if (t >= vesting.end) return vesting.amount;
+      return Math.floor((vesting.amount * (t - vesting.start)) / (vesting.end - vesting.start));
+    };
+
+    it('should forfeit only the unvested remainder', () => {
+      const vesting = { amount: 1_000_000, start: 0, end: 1_000 };
+      const released = 250_000;
+      const vested = vestedAt(vesting, 400);
+
+      expect(vesting.amount - vested).toBe(600_000);
+      expect(vested - released).toBe(150_000); // Still claimable
+    });
+
+    it('should only accept penalty or ban resolutions on resolved alerts', () => {
+      const qualifies = (status: string, resolution: string | null) =>
+        status === 'Resolved' && (resolution === 'Penalty' || resolution === 'Ban');
+
+      expect(qualifies('Resolved', 'Ban')).toBe(true);
+      expect(qualifies('Resolved', 'Warning')).toBe(false);
+      expect(qualifies('UnderInvestigation', 'Ban')).toBe(false);
+    });
+
+    it('should mark alerts resolved with no action as false positives', () => {
+      const resolve = (resolution: string) => (resolution === 'NoAction' ? 'FalsePositive' : 'Resolved');
+
+      expect(resolve('Ban')).toBe('Resolved');
+      expect(resolve('NoAction')).toBe('FalsePositive');
+    });
+
+    it('should stop vesting at the revocation time', () => {
+      const vesting = { amount: 1_000_000, start: 0, end: 1_000, revokeTime: 400 };
+
+      expect(vestedAt(vesting, 400)).toBe(400_000);
+      expect(vestedAt(vesting, 2_000)).toBe(400_000);
+    });
+  });
+
+  describe('holder_distribution', () => {
+    const DAY = 24 * 60 * 60;
+
+    it('should split the remainder between creator and holders', () => {
+      const remaining = 1_000_001;
+      const held = Math.floor(remaining / 2);
+      const creatorAmount = remaining - held;
+
+      expect(creatorAmount + held).toBe(remaining);
+      expect(creatorAmount).toBe(500_001);
+    });
+
+    it('should derive one claim receipt per holder', () => {
+      const distribution = Keypair.generate().publicKey;
+      const holder = Keypair.generate().publicKey;
+      const programId = Keypair.generate().publicKey;
+      const [first] = PublicKey.findProgramAddressSync(
+        [Buffer.from('distribution_claim'), distribution.toBuffer(), holder.toBuffer()],
+        programId
+      );
+      const [second] = PublicKey.findProgramAddressSync(
+        [Buffer.from('distribution_claim'), distribution.toBuffer(), holder.toBuffer()],
+        programId
+      );
+
+      expect(first.equals(second)).toBe(true);
+    });
+
+    it('should only allow sweeping after the claim deadline', () => {
+      const createdAt = 1_700_000_000;
+      const claimDeadline = createdAt + 90 * DAY;
+
+      expect(claimDeadline - 1 > claimDeadline).toBe(false);
+      expect(claimDeadline + 1 > claimDeadline).toBe(true);
+    });
+  });
+
+  describe('vesting_position', () => {
+    it('should keep the vesting PDA stable when the position changes hands', () => {
+      const mint = Keypair.generate().publicKey;
+      const beneficiary = Keypair.generate().publicKey;
+      const programId = Keypair.generate().publicKey;
+      const [beforeTransfer] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vesting'), mint.toBuffer(), beneficiary.toBuffer()],
+        programId
+      );
+
+      const vesting = { owner: beneficiary, beneficiary };
+      vesting.owner = Keypair.generate().publicKey;
+      const [afterTransfer] = PublicKey.findProgramAddressSync(
+        [Buffer.from('vesting'), mint.toBuffer(), vesting.beneficiary.toBuffer()],
+        programId
+      );
+
+      expect(afterTransfer.equals(beforeTransfer)).toBe(true);
+      expect(vesting.owner.equals(beneficiary)).toBe(false);
+    });
+
+    it('should reject transfers inside the minimum holding period', () => {
+      const minHoldingPeriod = 7 * 24 * 60 * 60;
+      const lastTransferTime = 1_700_000_000;
+      const now = lastTransferTime + minHoldingPeriod - 1;
+
+      try {
+        if (now < lastTransferTime + minHoldingPeriod) {
+          throw new Error('Minimum holding period not met');
+        }
+      } catch (error) {
+        expect(error.message).toContain('Minimum holding period not met');
+      }
+    });
+
+    it('should only let the recorded owner holding the frozen position NFT claim', () => {
+      const positionMint = Keypair.generate().publicKey;
+      const owner = Keypair.generate().publicKey;
+      const buyer = Keypair.generate().publicKey;
+      const vesting = { owner, positionMint };
+
+      type PositionAccount = { mint: PublicKey; owner: PublicKey; amount: number; isFrozen: boolean };
+      const authorizeHolder = (holder: PublicKey, account?: PositionAccount) => {
+        if (
+          !account ||
+          !vesting.owner.equals(holder) ||
+          !account.mint.equals(vesting.positionMint) ||
+          !account.owner.equals(holder) ||
+          account.amount !== 1 ||
+          !account.isFrozen
+        ) {
+          throw new Error('Unauthorized');
+        }
+      };
+
+      const ownerPositionAccount = { mint: positionMint, owner, amount: 1, isFrozen: true };
+      expect(() => authorizeHolder(owner, ownerPositionAccount)).not.toThrow();
+
+      // Position NFTs never leave the program's freeze, so nothing moved outside it is honoured
+      const buyerPositionAccount = { mint: positionMint, owner: buyer, amount: 1, isFrozen: false };
+      expect(() => authorizeHolder(buyer, buyerPositionAccount)).toThrow('Unauthorized');
+      expect(() => authorizeHolder(owner)).toThrow('Unauthorized');
+    });
+  });
+
+  describe('launch_pass', () => {
+    it('should consume a valid launch pass on token creation', () => {
+      const launchPass = {
+        owner: creator.publicKey,
+        isUsed: false,
+        usedForToken: null as PublicKey | null,
+        expiresAt: Math.floor(Date.now() / 1000) + 3600
+      };
+      const now = Math.floor(Date.now() / 1000);
+
+      expect(launchPass.isUsed).toBe(false);
+      expect(now < launchPass.expiresAt).toBe(true);
+
+      launchPass.isUsed = true;
+      launchPass.usedForToken = mint.publicKey;
+
+      expect(launchPass.isUsed).toBe(true);
+      expect(launchPass.usedForToken).toEqual(mint.publicKey);
+    });
+
+    it('should reject used or expired launch passes', () => {
+      const now = Math.floor(Date.now() / 1000);
+      const usedPass = { isUsed: true, expiresAt: null };
+      const expiredPass = { isUsed: false, expiresAt: now - 1 };
+
+      try {
+        if (usedPass.isUsed) {
+          throw new Error('Launch pass already used');
+        }
+      } catch (error) {
+        expect(error.message).toContain('already used');
+      }
+
+      try {
+        if (expiredPass.expiresAt !== null && now >= expiredPass.expiresAt) {
+          throw new Error('Launch pass expired');
+        }
+      } catch (error) {
+        expect(error.message).toContain('expired');
+      }
+    });
+
+    it('should reject a launch pass held by another wallet', () => {
+      const other = Keypair.generate();
+      const passTokenAccount = { owner: other.publicKey, amount: 1 };
+
+      expect(passTokenAccount.owner.equals(creator.publicKey)).toBe(false);
+    });
+
+    it('should only accept the launch pass bound to the creator profile', () => {
+      const boundPass = Keypair.generate().publicKey;
+      const otherPass = Keypair.generate().publicKey;
+      const profile = { launchPassMint: boundPass as PublicKey | null };
+
+      const consume = (passMint: PublicKey) => {
+        if (!profile.launchPassMint || !profile.launchPassMint.equals(passMint)) {
+          throw new Error('Invalid launch pass');
+        }
+        profile.launchPassMint = null;
+      };
+
+      expect(() => consume(otherPass)).toThrow('Invalid launch pass');
+      consume(boundPass);
+      expect(profile.launchPassMint).toBeNull();
+
+      // A consumed binding must be replaced before the next launch
+      expect(() => consume(boundPass)).toThrow('Invalid launch pass');
+    });
+  });
+
   describe('token_validation', () => {
     it('should validate token name length', () => {
       const validName = 'TestMeme';